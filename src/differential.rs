@@ -0,0 +1,94 @@
+//! Differential checks of the intrinsic-backed operations against k256's
+//! software implementation. Every check recomputes the operation on the same
+//! inputs and panics with the inputs if the results diverge, localizing a
+//! faulty intrinsic to the exact call instead of a wrong final proof. An
+//! input point k256 rejects as off the curve is a divergence as well: the
+//! intrinsic accepted it, so there is no reference result to compare against.
+
+use core::fmt::Arguments;
+
+use k256::{
+    elliptic_curve::{
+        ops::Reduce,
+        sec1::{FromEncodedPoint, ToEncodedPoint},
+    },
+    AffinePoint, EncodedPoint, ProjectivePoint, Scalar, U256,
+};
+
+use crate::elliptic_curve::HasNeutral;
+use crate::secp256k1::{Secp256k1Point, Secp256k1Scalar};
+
+/// `None` if k256 rejects the coordinates as not on the curve
+fn to_k256_point(point: &Secp256k1Point) -> Option<ProjectivePoint> {
+    if *point == Secp256k1Point::neutral() {
+        return Some(ProjectivePoint::IDENTITY);
+    }
+
+    let encoded = EncodedPoint::from_bytes(point.to_sec1_bytes(false)).ok()?;
+    Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded)).map(Into::into)
+}
+
+fn from_k256_point(point: ProjectivePoint) -> Secp256k1Point {
+    if point == ProjectivePoint::IDENTITY {
+        return Secp256k1Point::neutral();
+    }
+
+    Secp256k1Point::from_sec1(point.to_affine().to_encoded_point(false).as_bytes()).unwrap()
+}
+
+fn to_k256_scalar(scalar: &Secp256k1Scalar) -> Scalar {
+    Scalar::reduce(U256::from(*scalar))
+}
+
+fn from_k256_scalar(scalar: Scalar) -> Secp256k1Scalar {
+    Secp256k1Scalar::try_from(U256::from(scalar)).unwrap()
+}
+
+fn check_point(result: &Secp256k1Point, expected: Option<ProjectivePoint>, call: Arguments) {
+    match expected {
+        Some(expected) => assert_eq!(
+            *result,
+            from_k256_point(expected),
+            "differential check failed for {call}"
+        ),
+        None => panic!("differential check failed for {call}: k256 rejects an input point"),
+    }
+}
+
+pub(crate) fn check_mul(p: &Secp256k1Point, s: &Secp256k1Scalar, result: &Secp256k1Point) {
+    let expected = to_k256_point(p).map(|p| p * to_k256_scalar(s));
+    check_point(result, expected, format_args!("smul: {s:?} * {p:?}"));
+}
+
+pub(crate) fn check_lin_comb(
+    s1: &Secp256k1Scalar,
+    p1: &Secp256k1Point,
+    s2: &Secp256k1Scalar,
+    p2: &Secp256k1Point,
+    result: &Secp256k1Point,
+) {
+    let expected = to_k256_point(p1)
+        .zip(to_k256_point(p2))
+        .map(|(p1, p2)| p1 * to_k256_scalar(s1) + p2 * to_k256_scalar(s2));
+    check_point(
+        result,
+        expected,
+        format_args!("comb: {s1:?} * {p1:?} + {s2:?} * {p2:?}"),
+    );
+}
+
+pub(crate) fn check_scalar_mul(a: &Secp256k1Scalar, b: &Secp256k1Scalar, result: &Secp256k1Scalar) {
+    let expected = from_k256_scalar(to_k256_scalar(a) * to_k256_scalar(b));
+    assert_eq!(
+        *result, expected,
+        "differential check failed for muls: {a:?} * {b:?}"
+    );
+}
+
+pub(crate) fn check_scalar_inverse(a: &Secp256k1Scalar, result: &Secp256k1Scalar) {
+    let expected = from_k256_scalar(to_k256_scalar(a).invert().unwrap_or(Scalar::ZERO));
+    assert_eq!(
+        *result, expected,
+        "differential check failed for sinv: {a:?}"
+    );
+}
@@ -1,30 +1,135 @@
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 
-use crate::elliptic_curve::{CheckedAdd, EllipticCurve, MultiplicativeInverse};
-use crate::elliptic_curve::{FromLeBytes, ToLeBytes};
+use crate::elliptic_curve::{
+    CheckedAdd, EcdsaCurve, HasZero, MultiplicativeInverse, RecoverableCurve,
+};
+use crate::elliptic_curve::{FromBeBytes, FromLeBytes, ToBeBytes, ToLeBytes};
+use crate::error::Error;
+use crate::hashing::{Hash256, Sha256};
+use sha2::Digest;
 
-/// ECDSA implementation that works with any type implementing the EllipticCurve trait
-pub struct ECDSA<C: EllipticCurve> {
+/// ECDSA implementation that works with any type implementing the EcdsaCurve trait
+pub struct ECDSA<C: EcdsaCurve> {
     _phantom: PhantomData<C>,
 }
 
+/// Why [`ECDSA::verify_observed`] rejected a signature
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+    /// `r` or `s` is zero
+    OutOfRange,
+    /// `s` is above `n / 2`
+    HighS,
+    /// The public key is the point at infinity
+    InvalidKey,
+    /// The x-coordinate of `u1*G + u2*Q` does not equal `r`
+    XMismatch,
+}
+
+/// Receives the reason for every rejected verification
+pub trait VerifyObserver {
+    fn rejected(&mut self, rejection: Rejection);
+}
+
+/// Discards every rejection
+impl VerifyObserver for () {
+    #[inline(always)]
+    fn rejected(&mut self, _rejection: Rejection) {}
+}
+
 /// ECDSA signature consisting of (r, s) components
 #[derive(Clone, Debug)]
-pub struct Signature<C: EllipticCurve> {
+pub struct Signature<C: EcdsaCurve> {
     pub r: C::Scalar,
     pub s: C::Scalar,
 }
 
+impl<C: EcdsaCurve> Signature<C> {
+    /// Returns the low-s form of the signature, so that `(r, s)` and `(r, -s)`
+    /// compare and hash identically
+    pub fn canonicalize(&self) -> Self {
+        if C::is_high(&self.s) {
+            Signature {
+                r: self.r,
+                s: -self.s,
+            }
+        } else {
+            *self
+        }
+    }
+
+    /// Parses the 65-byte Ethereum encoding `r || s || v` with big-endian
+    /// `r` and `s`. `v` may be the raw parity (0, 1), the legacy 27/28 form,
+    /// or an EIP-155 value `chain_id * 2 + 35 + parity`
+    pub fn from_eth_bytes(bytes: &[u8; 65]) -> Result<(Self, RecoveryId), Error> {
+        let r = C::Scalar::from_be_bytes(&bytes[..32]).ok_or(Error::InvalidScalar)?;
+        let s = C::Scalar::from_be_bytes(&bytes[32..64]).ok_or(Error::InvalidScalar)?;
+
+        let parity = match bytes[64] {
+            v @ (0 | 1) => v,
+            v @ (27 | 28) => v - 27,
+            v if v >= 35 => (v - 35) % 2,
+            _ => return Err(Error::InvalidRecoveryId),
+        };
+
+        Ok((Signature { r, s }, RecoveryId(parity)))
+    }
+
+    /// Encodes the signature as the 65-byte Ethereum `r || s || v` blob with
+    /// `v` in the legacy 27/28 form. Recovery ids with the x-reduced bit set
+    /// cannot be expressed in this format
+    pub fn to_eth_bytes(&self, recovery_id: &RecoveryId) -> Result<[u8; 65], Error> {
+        if recovery_id.is_x_reduced() {
+            return Err(Error::InvalidRecoveryId);
+        }
+
+        let mut bytes = [0u8; 65];
+        self.r.write_be_bytes(&mut bytes[..32]);
+        self.s.write_be_bytes(&mut bytes[32..64]);
+        bytes[64] = 27 + recovery_id.0;
+
+        Ok(bytes)
+    }
+}
+
+impl<C: EcdsaCurve> Copy for Signature<C> {}
+
+impl<C: EcdsaCurve> PartialEq for Signature<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.r == other.r && self.s == other.s
+    }
+}
+
+impl<C: EcdsaCurve> Eq for Signature<C> {}
+
+impl<C: EcdsaCurve> Hash for Signature<C>
+where
+    C::Scalar: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.r.hash(state);
+        self.s.hash(state);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RecoveryId(u8);
 
 impl RecoveryId {
+    /// Accepts `y_odd | x_reduced << 1`, i.e. 0 through 3. Earlier versions
+    /// also accepted 4, which has no meaning and recovered the same key as 0
     pub fn new(recid: u8) -> Option<RecoveryId> {
-        if recid <= 4 {
+        if recid <= 3 {
             Some(RecoveryId(recid))
         } else {
             None
         }
     }
+
+    pub fn to_byte(&self) -> u8 {
+        self.0
+    }
 }
 
 impl RecoveryId {
@@ -37,20 +142,226 @@ impl RecoveryId {
     }
 }
 
-impl<C: EllipticCurve + 'static> ECDSA<C> {
+/// A 32-byte message digest, as opposed to a raw [`Message`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Prehash(pub [u8; 32]);
+
+/// A raw message, hashed before verification: with SHA-256 by
+/// [`ECDSA::verify_msg`], or the selected hash by [`ECDSA::verify_msg_with`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Message<'a>(pub &'a [u8]);
+
+impl From<[u8; 32]> for Prehash {
+    fn from(hash: [u8; 32]) -> Self {
+        Prehash(hash)
+    }
+}
+
+impl<'a> From<&'a [u8]> for Message<'a> {
+    fn from(message: &'a [u8]) -> Self {
+        Message(message)
+    }
+}
+
+/// Signature bundled with the recovery id needed to recover its public key
+#[derive(Clone, Debug)]
+pub struct RecoverableSignature<C: EcdsaCurve> {
+    pub signature: Signature<C>,
+    pub recovery_id: RecoveryId,
+}
+
+impl<C: EcdsaCurve> RecoverableSignature<C> {
+    /// Parses the 65-byte Ethereum encoding `r || s || v`, see
+    /// [`Signature::from_eth_bytes`]
+    pub fn from_bytes(bytes: &[u8; 65]) -> Result<Self, Error> {
+        Signature::from_eth_bytes(bytes).map(Self::from)
+    }
+
+    /// Encodes the signature as the 65-byte Ethereum `r || s || v` blob, see
+    /// [`Signature::to_eth_bytes`]
+    pub fn to_bytes(&self) -> Result<[u8; 65], Error> {
+        self.signature.to_eth_bytes(&self.recovery_id)
+    }
+}
+
+impl<C: RecoverableCurve + 'static> RecoverableSignature<C> {
+    /// Recovers the public key that produced the signature over `hash`
+    pub fn recover(&self, hash: &[u8; 32]) -> Result<C, Error> {
+        ECDSA::recover_key(hash, &self.signature, &self.recovery_id)
+    }
+}
+
+impl<C: EcdsaCurve> Copy for RecoverableSignature<C> {}
+
+impl<C: EcdsaCurve> PartialEq for RecoverableSignature<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.signature == other.signature && self.recovery_id == other.recovery_id
+    }
+}
+
+impl<C: EcdsaCurve> Eq for RecoverableSignature<C> {}
+
+impl<C: EcdsaCurve> From<(Signature<C>, RecoveryId)> for RecoverableSignature<C> {
+    fn from((signature, recovery_id): (Signature<C>, RecoveryId)) -> Self {
+        RecoverableSignature {
+            signature,
+            recovery_id,
+        }
+    }
+}
+
+impl<C: EcdsaCurve> From<RecoverableSignature<C>> for Signature<C> {
+    fn from(recoverable: RecoverableSignature<C>) -> Self {
+        recoverable.signature
+    }
+}
+
+impl<C: EcdsaCurve + 'static> ECDSA<C> {
+    /// Converts a hash of any length to a scalar with RFC 6979's bits2int,
+    /// keeping the leftmost `ORDER_BITS` bits, then reduces it modulo n
+    pub fn bits2int(hash: &[u8]) -> C::Scalar {
+        let order_bytes = C::ORDER_BITS.div_ceil(8);
+        let taken = hash.len().min(order_bytes);
+
+        let mut bytes = C::ScalarBytes::default();
+        let buffer = bytes.as_mut();
+        let offset = buffer.len() - taken;
+        buffer[offset..].copy_from_slice(&hash[..taken]);
+
+        let excess = if hash.len() * 8 > C::ORDER_BITS {
+            taken * 8 - C::ORDER_BITS
+        } else {
+            0
+        };
+        if excess > 0 {
+            for i in (offset..buffer.len()).rev() {
+                let carry = if i > offset {
+                    buffer[i - 1] << (8 - excess)
+                } else {
+                    0
+                };
+                buffer[i] = (buffer[i] >> excess) | carry;
+            }
+        }
+
+        C::reduce_be_bytes(&bytes)
+    }
+
+    /// Verify a signature over a hash of any length, truncated with bits2int
+    pub fn verify_hash_bytes(hash: &[u8], signature: &Signature<C>, public_key: &C) -> bool {
+        Self::verify_reduced(Self::bits2int(hash), signature, public_key)
+    }
+
+    /// Verify a signature over the output of the given digest
+    pub fn verify_digest<D: Digest>(digest: D, signature: &Signature<C>, public_key: &C) -> bool {
+        Self::verify_hash_bytes(&digest.finalize(), signature, public_key)
+    }
+
     /// Verify a signature using the public key
     pub fn verify(hash: &[u8; 32], signature: &Signature<C>, public_key: &C) -> bool {
-        let z = C::reduce_hash(hash);
+        Self::verify_reduced(C::reduce_hash(hash), signature, public_key)
+    }
+
+    /// Verify a signature over a digest the caller already computed
+    pub fn verify_prehash(prehash: &Prehash, signature: &Signature<C>, public_key: &C) -> bool {
+        Self::verify(&prehash.0, signature, public_key)
+    }
+
+    /// Verify a signature over the SHA-256 hash of a raw message
+    pub fn verify_msg(message: Message, signature: &Signature<C>, public_key: &C) -> bool {
+        Self::verify_msg_with::<Sha256>(message, signature, public_key)
+    }
+
+    /// Verify a signature over the hash `H` of a raw message, e.g.
+    /// [`Keccak256`](crate::hashing::Keccak256) for guests that already
+    /// hash with it elsewhere
+    pub fn verify_msg_with<H: Hash256>(
+        message: Message,
+        signature: &Signature<C>,
+        public_key: &C,
+    ) -> bool {
+        Self::verify(&H::hash(message.0), signature, public_key)
+    }
+
+    /// Verify a signature against several candidate public keys, returning
+    /// the index of the first that verifies. The inversion and the scalars
+    /// `u1`, `u2` are computed once and shared across candidates
+    pub fn verify_any(
+        hash: &[u8; 32],
+        signature: &Signature<C>,
+        candidates: &[C],
+    ) -> Option<usize> {
+        let (u1, u2) = Self::verification_scalars(C::reduce_hash(hash), signature).ok()?;
+
+        candidates.iter().position(|public_key| {
+            public_key.is_valid_public_key()
+                && C::lin_comb(&u1, C::generator(), &u2, public_key).get_x_coord() == signature.r
+        })
+    }
+
+    /// Like [`ECDSA::verify`], but reports why a signature is rejected to
+    /// `observer`. With the no-op `()` observer the reporting compiles away
+    pub fn verify_observed<O: VerifyObserver>(
+        hash: &[u8; 32],
+        signature: &Signature<C>,
+        public_key: &C,
+        observer: &mut O,
+    ) -> bool {
+        match Self::check_reduced(C::reduce_hash(hash), signature, public_key) {
+            Ok(()) => true,
+            Err(rejection) => {
+                observer.rejected(rejection);
+                false
+            }
+        }
+    }
+
+    fn verify_reduced(z: C::Scalar, signature: &Signature<C>, public_key: &C) -> bool {
+        Self::check_reduced(z, signature, public_key).is_ok()
+    }
+
+    fn check_reduced(
+        z: C::Scalar,
+        signature: &Signature<C>,
+        public_key: &C,
+    ) -> Result<(), Rejection> {
+        let (u1, u2) = Self::verification_scalars(z, signature)?;
+
+        // With Q at infinity, u1*G alone could be made to match r
+        if !public_key.is_valid_public_key() {
+            return Err(Rejection::InvalidKey);
+        }
+
+        // Compute the point P = u1*G + u2*Q
+        let p = C::lin_comb(&u1, C::generator(), &u2, public_key);
+
+        // Extract x-coordinate of P as v
+        let v = p.get_x_coord();
+
+        // Verify that v = r
+        if v == signature.r {
+            Ok(())
+        } else {
+            Err(Rejection::XMismatch)
+        }
+    }
+
+    /// Computes `(u1, u2) = (z * s^-1, r * s^-1)`, or the reason the
+    /// signature is out of range or not low-s
+    fn verification_scalars(
+        z: C::Scalar,
+        signature: &Signature<C>,
+    ) -> Result<(C::Scalar, C::Scalar), Rejection> {
         let r = signature.r;
         let s = signature.s;
 
         // Check if r and s are in valid range (non-zero)
-        if r == C::Scalar::default() || s == C::Scalar::default() {
-            return false;
+        if r.is_zero() || s.is_zero() {
+            return Err(Rejection::OutOfRange);
         }
 
         if C::is_high(&s) {
-            return false;
+            return Err(Rejection::HighS);
         }
 
         // Compute s^-1
@@ -62,39 +373,52 @@ impl<C: EllipticCurve + 'static> ECDSA<C> {
         // Compute u2 = r * s^-1
         let u2 = r * &s_inv;
 
-        // Compute the point P = u1*G + u2*Q
-        let p = C::lin_comb(&u1, C::generator(), &u2, public_key);
-
-        // Extract x-coordinate of P as v
-        let v = p.get_x_coord();
-
-        // Verify that v = r
-        v == r
+        Ok((u1, u2))
     }
+}
 
+impl<C: RecoverableCurve + 'static> ECDSA<C> {
+    /// Recovers the public key that produced the signature over `hash`.
+    /// Fails for a zero `r` or `s`, or if `r` is not the x-coordinate of a
+    /// point
     pub fn recover(
         hash: &[u8; 32],
         signature: &Signature<C>,
         recovery_id: &RecoveryId,
     ) -> Result<C, ()> {
+        Self::recover_key(hash, signature, recovery_id).map_err(|_| ())
+    }
+
+    /// [`ECDSA::recover`], failing with [`Error::InvalidScalar`] for a zero
+    /// `r` or `s` and [`Error::InvalidPoint`] if `r` does not decompress
+    pub(crate) fn recover_key(
+        hash: &[u8; 32],
+        signature: &Signature<C>,
+        recovery_id: &RecoveryId,
+    ) -> Result<C, Error> {
         let r = signature.r;
         let s = signature.s;
 
+        // A zero r has no inverse, and a zero s recovers a key from z alone
+        if r.is_zero() || s.is_zero() {
+            return Err(Error::InvalidScalar);
+        }
+
         let is_y_odd = recovery_id.is_y_odd();
         let is_x_reduced = recovery_id.is_x_reduced();
 
-        let bytes_le = if is_x_reduced {
-            C::Uint::to_le_bytes(
-                &C::Uint::from_le_bytes(&r.to_le_bytes())
-                    .ok_or(())?
-                    .checked_add(&C::ORDER)
-                    .ok_or(())?,
-            )
-        } else {
-            r.to_le_bytes()
-        };
+        let mut bytes_le = C::FieldBytes::default();
+        r.write_le_bytes(&mut bytes_le.as_mut()[..C::SCALAR_BYTES]);
+
+        if is_x_reduced {
+            C::Uint::from_le_bytes(bytes_le.as_ref())
+                .ok_or(Error::InvalidPoint)?
+                .checked_add(&C::ORDER)
+                .ok_or(Error::InvalidPoint)?
+                .write_le_bytes(bytes_le.as_mut());
+        }
 
-        let point_r = C::decompress(&bytes_le, is_y_odd).ok_or(())?;
+        let point_r = C::decompress(bytes_le.as_ref(), is_y_odd).ok_or(Error::InvalidPoint)?;
 
         let z = C::reduce_hash(&hash);
 
@@ -108,3 +432,293 @@ impl<C: EllipticCurve + 'static> ECDSA<C> {
         Ok(q_a)
     }
 }
+
+/// Verifies a signature over a hashed message that is fed in chunks, so
+/// large payloads never need to be buffered in full. The hash defaults to
+/// SHA-256
+pub struct VerifierHasher<C: EcdsaCurve, H = Sha256> {
+    hasher: H,
+    _phantom: PhantomData<C>,
+}
+
+impl<C: EcdsaCurve + 'static> VerifierHasher<C> {
+    pub fn new() -> Self {
+        Self::with_hash()
+    }
+}
+
+impl<C: EcdsaCurve + 'static, H: Hash256> VerifierHasher<C, H> {
+    /// Starts hashing the message with `H`
+    pub fn with_hash() -> Self {
+        VerifierHasher {
+            hasher: H::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Appends a chunk of the message
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.hasher.update(chunk);
+    }
+
+    /// Finishes hashing the message and verifies the signature over its digest
+    pub fn finalize_verify(self, signature: &Signature<C>, public_key: &C) -> bool {
+        let hash = self.hasher.finalize();
+        ECDSA::verify(&hash, signature, public_key)
+    }
+}
+
+impl<C: EcdsaCurve + 'static> Default for VerifierHasher<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::{HasGenerator, HasNeutral};
+    use crate::hashing::Keccak256;
+    use crate::secp256k1::{Secp256k1Point, Secp256k1Scalar};
+    use crate::test_fixtures::{known_answer, scalar, sign, KnownAnswer};
+
+    type Ecdsa = ECDSA<Secp256k1Point>;
+
+    impl VerifyObserver for Vec<Rejection> {
+        fn rejected(&mut self, rejection: Rejection) {
+            self.push(rejection);
+        }
+    }
+
+    #[test]
+    fn bits2int_truncates_and_pads_hashes() {
+        let KnownAnswer {
+            message,
+            hash,
+            public_key,
+            signature,
+            ..
+        } = known_answer();
+
+        let mut long = [0xaa; 64];
+        long[..32].copy_from_slice(&hash);
+        assert!(Ecdsa::verify_hash_bytes(&long, &signature, &public_key));
+        assert!(Ecdsa::verify_hash_bytes(&hash, &signature, &public_key));
+        assert!(!Ecdsa::verify_hash_bytes(
+            &hash[1..],
+            &signature,
+            &public_key
+        ));
+
+        let mut padded = [0; 32];
+        padded[12..].copy_from_slice(&hash[..20]);
+        assert_eq!(
+            Ecdsa::bits2int(&hash[..20]),
+            Secp256k1Point::reduce_hash(&padded)
+        );
+
+        assert!(Ecdsa::verify_digest(
+            sha2::Sha256::new_with_prefix(message),
+            &signature,
+            &public_key
+        ));
+    }
+
+    #[test]
+    fn prehash_and_message_verification() {
+        let KnownAnswer {
+            message,
+            hash,
+            public_key,
+            signature,
+            ..
+        } = known_answer();
+
+        assert!(Ecdsa::verify_prehash(
+            &Prehash(hash),
+            &signature,
+            &public_key
+        ));
+        assert!(Ecdsa::verify_msg(Message(message), &signature, &public_key));
+
+        // The digest passed as a raw message gets hashed a second time
+        assert!(!Ecdsa::verify_msg(Message(&hash), &signature, &public_key));
+    }
+
+    #[test]
+    fn streaming_verification() {
+        let KnownAnswer {
+            public_key,
+            signature,
+            ..
+        } = known_answer();
+
+        let mut hasher = VerifierHasher::new();
+        hasher.update(b"valida-secp256k1 ");
+        hasher.update(b"self check");
+        assert!(hasher.finalize_verify(&signature, &public_key));
+
+        let mut hasher = VerifierHasher::new();
+        hasher.update(b"valida-secp256k1 self check!");
+        assert!(!hasher.finalize_verify(&signature, &public_key));
+    }
+
+    #[test]
+    fn message_hash_is_selectable() {
+        let message = b"valida-secp256k1 keccak";
+        let key = scalar(7);
+        let public_key = *Secp256k1Point::generator() * key;
+        let signature = sign(key, scalar(11), &Keccak256::hash(message)).signature;
+
+        assert!(Ecdsa::verify_msg_with::<Keccak256>(
+            Message(message),
+            &signature,
+            &public_key
+        ));
+        assert!(!Ecdsa::verify_msg(
+            Message(message),
+            &signature,
+            &public_key
+        ));
+
+        let mut hasher = VerifierHasher::<_, Keccak256>::with_hash();
+        hasher.update(b"valida-secp256k1 ");
+        hasher.update(b"keccak");
+        assert!(hasher.finalize_verify(&signature, &public_key));
+    }
+
+    #[test]
+    fn verify_any_finds_the_signing_key() {
+        let KnownAnswer {
+            hash,
+            public_key,
+            signature,
+            ..
+        } = known_answer();
+        let two_g = Secp256k1Point::generator().mul_small(2);
+
+        let candidates = [two_g, public_key, public_key];
+        assert_eq!(Ecdsa::verify_any(&hash, &signature, &candidates), Some(1));
+        assert_eq!(Ecdsa::verify_any(&hash, &signature, &candidates[..1]), None);
+        assert_eq!(Ecdsa::verify_any(&hash, &signature, &[]), None);
+
+        let high_s = Signature {
+            r: signature.r,
+            s: -signature.s,
+        };
+        assert_eq!(Ecdsa::verify_any(&hash, &high_s, &candidates), None);
+    }
+
+    #[test]
+    fn verify_observed_reports_rejections() {
+        let KnownAnswer {
+            hash,
+            public_key,
+            signature,
+            ..
+        } = known_answer();
+
+        let mut rejections = Vec::new();
+        let mut observe = |hash: &[u8; 32], signature: &Signature<Secp256k1Point>| {
+            Ecdsa::verify_observed(hash, signature, &public_key, &mut rejections)
+        };
+
+        assert!(observe(&hash, &signature));
+        assert!(!observe(&[0; 32], &signature));
+        assert!(!observe(
+            &hash,
+            &Signature {
+                r: signature.r,
+                s: -signature.s
+            }
+        ));
+        assert!(!observe(
+            &hash,
+            &Signature {
+                r: Secp256k1Scalar::ZERO,
+                s: signature.s
+            }
+        ));
+        assert_eq!(
+            rejections,
+            [
+                Rejection::XMismatch,
+                Rejection::HighS,
+                Rejection::OutOfRange
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_rejects_the_neutral_key() {
+        let hash = known_answer().hash;
+        let g = *Secp256k1Point::generator();
+
+        // Against the point at infinity u2*Q vanishes, so s = 1 and
+        // r = x(z*G) would pass the x-coordinate comparison alone
+        let z = Secp256k1Point::reduce_hash(&hash);
+        let forged = Signature {
+            r: (g * z).get_x_coord(),
+            s: Secp256k1Scalar::ONE,
+        };
+        let neutral = Secp256k1Point::neutral();
+
+        let mut rejections = Vec::new();
+        assert!(!Ecdsa::verify(&hash, &forged, &neutral));
+        assert!(!Ecdsa::verify_observed(
+            &hash,
+            &forged,
+            &neutral,
+            &mut rejections
+        ));
+        assert_eq!(rejections, [Rejection::InvalidKey]);
+        assert_eq!(Ecdsa::verify_any(&hash, &forged, &[neutral]), None);
+    }
+
+    #[test]
+    fn recoverable_signature_roundtrips_and_recovers() {
+        let KnownAnswer {
+            hash,
+            public_key,
+            signature,
+            recovery_id,
+            ..
+        } = known_answer();
+        let recoverable = RecoverableSignature::from((signature, recovery_id));
+
+        let bytes = recoverable.to_bytes().unwrap();
+        assert_eq!(bytes[..32], signature.r.to_be_bytes());
+        assert_eq!(bytes[64], 27 + recovery_id.to_byte());
+        assert_eq!(RecoverableSignature::from_bytes(&bytes), Ok(recoverable));
+        assert_eq!(Signature::from(recoverable), signature);
+
+        assert_eq!(recoverable.recover(&hash), Ok(public_key));
+    }
+
+    #[test]
+    fn recovery_rejects_zero_components() {
+        let KnownAnswer {
+            hash,
+            signature,
+            recovery_id,
+            ..
+        } = known_answer();
+
+        for zeroed in [
+            Signature {
+                r: Secp256k1Scalar::ZERO,
+                s: signature.s,
+            },
+            Signature {
+                r: signature.r,
+                s: Secp256k1Scalar::ZERO,
+            },
+        ] {
+            assert_eq!(Ecdsa::recover(&hash, &zeroed, &recovery_id), Err(()));
+            assert_eq!(
+                RecoverableSignature::from((zeroed, recovery_id)).recover(&hash),
+                Err(Error::InvalidScalar)
+            );
+        }
+    }
+}
@@ -27,12 +27,42 @@ pub trait FromLeBytes {
 
 pub trait ToLeBytes {
     fn to_le_bytes(&self) -> Vec<u8>;
+
+    /// Writes the little-endian encoding into `out`, which must have exactly
+    /// the encoding's length. Implementors should override this to avoid the
+    /// allocation of [`ToLeBytes::to_le_bytes`]
+    fn write_le_bytes(&self, out: &mut [u8]) {
+        out.copy_from_slice(&self.to_le_bytes());
+    }
+}
+
+pub trait FromBeBytes {
+    fn from_be_bytes(bytes: &[u8]) -> Option<Self>
+    where
+        Self: Sized;
+}
+
+pub trait ToBeBytes {
+    fn to_be_bytes(&self) -> Vec<u8>;
+
+    /// Writes the big-endian encoding into `out`, which must have exactly
+    /// the encoding's length. Implementors should override this to avoid the
+    /// allocation of [`ToBeBytes::to_be_bytes`]
+    fn write_be_bytes(&self, out: &mut [u8]) {
+        out.copy_from_slice(&self.to_be_bytes());
+    }
 }
 
 pub trait HasSqrt: Sized {
     fn sqrt(&self) -> Option<Self>;
 }
 
+pub trait HasZero: Sized {
+    const ZERO: Self;
+
+    fn is_zero(&self) -> bool;
+}
+
 pub trait IsOdd {
     fn is_odd(&self) -> bool;
 }
@@ -41,41 +71,130 @@ pub trait CheckedAdd: Sized {
     fn checked_add(&self, rhs: &Self) -> Option<Self>;
 }
 
-/// Trait defining the operations required for an elliptic curve
-pub trait EllipticCurve:
+/// Arithmetic required of a curve's scalar field. Implemented for every type
+/// providing the operations
+pub trait ScalarArith:
     Clone
     + Copy
     + Debug
+    + Default
+    + Eq
+    + HasZero
     + Add<Output = Self>
-    + Mul<Self::Scalar, Output = Self>
-    + HasGenerator
-    + HasNeutral
+    + for<'a> Add<&'a Self, Output = Self>
+    + Mul<Output = Self>
+    + for<'a> Mul<&'a Self, Output = Self>
+    + MultiplicativeInverse
+    + FromLeBytes
+    + ToLeBytes
+    + FromBeBytes
+    + ToBeBytes
+    + Neg<Output = Self>
 {
-    /// The scalar field type
-    type Scalar: Clone
+}
+
+impl<T> ScalarArith for T where
+    T: Clone
         + Copy
         + Debug
         + Default
         + Eq
-        + Mul<Output = <Self as EllipticCurve>::Scalar>
-        + for<'a> Mul<&'a <Self as EllipticCurve>::Scalar, Output = <Self as EllipticCurve>::Scalar>
+        + HasZero
+        + Add<Output = T>
+        + for<'a> Add<&'a T, Output = T>
+        + Mul<Output = T>
+        + for<'a> Mul<&'a T, Output = T>
         + MultiplicativeInverse
+        + FromLeBytes
         + ToLeBytes
-        + Neg<Output = Self::Scalar>;
+        + FromBeBytes
+        + ToBeBytes
+        + Neg<Output = T>
+{
+}
 
-    type Uint: CheckedAdd + FromLeBytes + ToLeBytes;
+/// Group operations on the points of an elliptic curve
+pub trait Group:
+    Clone
+    + Copy
+    + Debug
+    + Add<Output = Self>
+    + Mul<Self::Scalar, Output = Self>
+    + HasGenerator
+    + HasNeutral
+{
+    /// The scalar field type
+    type Scalar: ScalarArith;
+
+    /// Fixed-size buffer holding an encoded scalar
+    type ScalarBytes: AsRef<[u8]> + AsMut<[u8]> + Default + Copy;
+
+    /// Length of an encoded scalar
+    const SCALAR_BYTES: usize;
+
+    fn lin_comb(s1: &Self::Scalar, p1: &Self, s2: &Self::Scalar, p2: &Self) -> Self;
+
+    /// Computes the n-term linear combination `s_1 * p_1 + ... + s_n * p_n`
+    fn lin_comb_iter<'a>(mut terms: impl Iterator<Item = (&'a Self::Scalar, &'a Self)>) -> Self
+    where
+        Self: 'a,
+    {
+        let mut acc = Self::neutral();
+        while let Some((s1, p1)) = terms.next() {
+            acc = acc
+                + match terms.next() {
+                    Some((s2, p2)) => Self::lin_comb(s1, p1, s2, p2),
+                    None => *p1 * *s1,
+                };
+        }
+        acc
+    }
+}
 
+/// Decoding of points from their coordinates
+pub trait PointCodec: Group {
+    /// Fixed-size buffer holding an encoded base field element
+    type FieldBytes: AsRef<[u8]> + AsMut<[u8]> + Default + Copy;
+
+    fn decompress(bytes_le: &[u8], is_y_odd: bool) -> Option<Self>;
+}
+
+/// Operations specific to ECDSA over the curve
+pub trait EcdsaCurve: Group {
     /// Extracts the x-coordinate as a scalar value
     fn get_x_coord(&self) -> Self::Scalar;
 
     /// Reduces a hash value to a scalar
     fn reduce_hash(hash: &[u8; 32]) -> Self::Scalar;
 
+    /// Reduces a big-endian integer of scalar size to a scalar
+    fn reduce_be_bytes(bytes: &Self::ScalarBytes) -> Self::Scalar;
+
     fn is_high(s: &Self::Scalar) -> bool;
 
-    fn lin_comb(s1: &Self::Scalar, p1: &Self, s2: &Self::Scalar, p2: &Self) -> Self;
+    /// Whether the point can serve as a public key, i.e. is not the point
+    /// at infinity. Being on the curve is left to the point constructors, so
+    /// verification does not recheck it
+    fn is_valid_public_key(&self) -> bool;
 
-    fn decompress(bytes_le: &[u8], is_y_odd: bool) -> Option<Self>;
+    /// Bit length of the group order
+    const ORDER_BITS: usize;
+}
+
+/// Trait defining the operations required for an elliptic curve, kept as an
+/// alias for curves providing all of [`Group`], [`PointCodec`] and
+/// [`EcdsaCurve`]
+pub trait EllipticCurve: Group + PointCodec + EcdsaCurve {}
+
+impl<T: Group + PointCodec + EcdsaCurve> EllipticCurve for T {}
+
+/// Public-key recovery from ECDSA signatures. Optional, so curves that don't
+/// support recovery need not provide the wide integer arithmetic it uses
+pub trait RecoverableCurve: EllipticCurve {
+    /// Unsigned integer wide enough to hold an encoded base field element
+    type Uint: CheckedAdd + FromLeBytes + ToLeBytes;
 
+    /// The group order as a base field integer, added back to `r` when the
+    /// x coordinate of the nonce point was reduced modulo n
     const ORDER: Self::Uint;
 }
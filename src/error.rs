@@ -0,0 +1,33 @@
+use std::fmt;
+
+/// Errors returned when parsing or validating encoded values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// A scalar is not in the range `[0, n)`
+    InvalidScalar,
+    /// A base field element is not in the range `[0, p)`
+    InvalidFieldElement,
+    /// A recovery id is out of range or cannot be encoded
+    InvalidRecoveryId,
+    /// A point encoding is malformed or does not describe a curve point
+    InvalidPoint,
+    /// An input or requested output has the wrong length
+    InvalidLength,
+    /// A known-answer test of [`crate::self_check`] failed
+    SelfCheckFailed(&'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidScalar => write!(f, "invalid scalar"),
+            Error::InvalidFieldElement => write!(f, "invalid field element"),
+            Error::InvalidRecoveryId => write!(f, "invalid recovery id"),
+            Error::InvalidPoint => write!(f, "invalid point encoding"),
+            Error::InvalidLength => write!(f, "invalid length"),
+            Error::SelfCheckFailed(test) => write!(f, "self check failed: {test}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
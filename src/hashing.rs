@@ -0,0 +1,116 @@
+//! 256-bit hash functions used by the crate, behind a single [`Hash256`]
+//! trait. Every internal consumer (streaming verification, transcripts,
+//! HMAC/HKDF) hashes through these types, so a Valida release exposing hash
+//! precompiles only has to swap the bodies here for the whole crate to pick
+//! them up. Until then they are backed by the software `sha2`/`sha3`
+//! implementations. The 160-bit hashes behind Bitcoin addresses sit here
+//! too, as plain functions.
+
+use sha2::Digest;
+
+/// Incremental hash with a 32-byte output
+pub trait Hash256: Clone {
+    fn new() -> Self;
+
+    fn update(&mut self, data: &[u8]);
+
+    fn finalize(self) -> [u8; 32];
+
+    /// Starts a BIP-340 tagged hash, prefixed with `H(tag) || H(tag)`
+    fn new_tagged(tag: &[u8]) -> Self {
+        let tag_hash = Self::hash(tag);
+        let mut hasher = Self::new();
+        hasher.update(&tag_hash);
+        hasher.update(&tag_hash);
+        hasher
+    }
+
+    /// One-shot hash of `data`
+    fn hash(data: &[u8]) -> [u8; 32] {
+        let mut hasher = Self::new();
+        hasher.update(data);
+        hasher.finalize()
+    }
+}
+
+/// SHA-256
+#[derive(Clone, Default)]
+pub struct Sha256(sha2::Sha256);
+
+impl Hash256 for Sha256 {
+    #[inline(always)]
+    fn new() -> Self {
+        Sha256(sha2::Sha256::new())
+    }
+
+    #[inline(always)]
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    #[inline(always)]
+    fn finalize(self) -> [u8; 32] {
+        self.0.finalize().into()
+    }
+}
+
+/// Keccak-256, as used by Ethereum (not NIST SHA3-256)
+#[derive(Clone, Default)]
+pub struct Keccak256(sha3::Keccak256);
+
+impl Hash256 for Keccak256 {
+    #[inline(always)]
+    fn new() -> Self {
+        Keccak256(sha3::Keccak256::new())
+    }
+
+    #[inline(always)]
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    #[inline(always)]
+    fn finalize(self) -> [u8; 32] {
+        self.0.finalize().into()
+    }
+}
+
+/// RIPEMD-160
+pub fn ripemd160(data: &[u8]) -> [u8; 20] {
+    ripemd::Ripemd160::digest(data).into()
+}
+
+/// Bitcoin's HASH160, `RIPEMD-160(SHA-256(data))`, the hash P2PKH and
+/// P2WPKH outputs commit to
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    ripemd160(&Sha256::hash(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_digests_of_empty_input() {
+        assert_eq!(
+            hex::encode(Sha256::hash(b"")),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex::encode(Keccak256::hash(b"")),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            hex::encode(ripemd160(b"")),
+            "9c1185a5c5e9fc54612808977ee8f548b2258d31"
+        );
+
+        let mut hasher = Sha256::new();
+        hasher.update(b"valida-secp256k1 ");
+        hasher.update(b"self check");
+        assert_eq!(
+            hasher.finalize(),
+            Sha256::hash(b"valida-secp256k1 self check")
+        );
+    }
+}
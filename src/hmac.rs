@@ -0,0 +1,128 @@
+//! HMAC-SHA256 (RFC 2104) and HKDF-SHA256 (RFC 5869) over the same SHA-256
+//! implementation the rest of the crate uses, so guests deriving keys or
+//! nonces don't link a second hashing stack.
+
+use crate::error::Error;
+use crate::hashing::{Hash256, Sha256};
+
+const BLOCK_SIZE: usize = 64;
+
+/// Largest output [`hkdf_expand`] can produce, 255 hash blocks
+pub const HKDF_MAX_OUTPUT: usize = 255 * 32;
+
+/// Incremental HMAC-SHA256
+#[derive(Clone)]
+pub struct HmacSha256 {
+    inner: Sha256,
+    outer: Sha256,
+}
+
+impl HmacSha256 {
+    pub fn new(key: &[u8]) -> Self {
+        let mut block = [0u8; BLOCK_SIZE];
+        if key.len() > BLOCK_SIZE {
+            block[..32].copy_from_slice(&Sha256::hash(key));
+        } else {
+            block[..key.len()].copy_from_slice(key);
+        }
+
+        let mut inner = Sha256::new();
+        let mut outer = Sha256::new();
+        inner.update(&block.map(|b| b ^ 0x36));
+        outer.update(&block.map(|b| b ^ 0x5c));
+
+        HmacSha256 { inner, outer }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    pub fn finalize(self) -> [u8; 32] {
+        let mut outer = self.outer;
+        outer.update(&self.inner.finalize());
+        outer.finalize()
+    }
+}
+
+/// One-shot HMAC-SHA256 of `data` under `key`
+pub fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    let mut mac = HmacSha256::new(key);
+    mac.update(data);
+    mac.finalize()
+}
+
+/// HKDF-Extract: condenses the input keying material into a pseudorandom key
+pub fn hkdf_extract(salt: &[u8], ikm: &[u8]) -> [u8; 32] {
+    hmac_sha256(salt, ikm)
+}
+
+/// HKDF-Expand: fills `okm` with output keying material bound to `info`.
+/// Fails if more than [`HKDF_MAX_OUTPUT`] bytes are requested
+pub fn hkdf_expand(prk: &[u8; 32], info: &[u8], okm: &mut [u8]) -> Result<(), Error> {
+    if okm.len() > HKDF_MAX_OUTPUT {
+        return Err(Error::InvalidLength);
+    }
+
+    let mut block = [0u8; 32];
+    for (i, chunk) in okm.chunks_mut(32).enumerate() {
+        let mut mac = HmacSha256::new(prk);
+        if i > 0 {
+            mac.update(&block);
+        }
+        mac.update(info);
+        mac.update(&[i as u8 + 1]);
+        block = mac.finalize();
+
+        chunk.copy_from_slice(&block[..chunk.len()]);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hmac_matches_rfc4231() {
+        assert_eq!(
+            hex::encode(hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+
+        let mut mac = HmacSha256::new(&[0xaa; 131]);
+        mac.update(b"Test Using Larger Than Block-Size Key - ");
+        mac.update(b"Hash Key First");
+        assert_eq!(
+            hex::encode(mac.finalize()),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+
+    #[test]
+    fn hkdf_matches_rfc5869() {
+        let salt: Vec<u8> = (0x00..=0x0c).collect();
+        let info: Vec<u8> = (0xf0..=0xf9).collect();
+
+        let prk = hkdf_extract(&salt, &[0x0b; 22]);
+        assert_eq!(
+            hex::encode(prk),
+            "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5"
+        );
+
+        let mut okm = [0u8; 42];
+        hkdf_expand(&prk, &info, &mut okm).unwrap();
+        assert_eq!(
+            hex::encode(okm),
+            "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf\
+             34007208d5b887185865"
+        );
+
+        let mut too_long = vec![0u8; HKDF_MAX_OUTPUT + 1];
+        assert_eq!(
+            hkdf_expand(&prk, &info, &mut too_long),
+            Err(Error::InvalidLength)
+        );
+    }
+}
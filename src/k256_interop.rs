@@ -0,0 +1,73 @@
+//! Conversions between this crate's types and their `k256::ecdsa`
+//! counterparts. Both sides encode the recovery id as `y_odd | x_reduced << 1`.
+
+use k256::ecdsa;
+
+use crate::ecdsa::RecoveryId;
+use crate::error::Error;
+use crate::secp256k1::Secp256k1Point;
+
+impl From<RecoveryId> for ecdsa::RecoveryId {
+    fn from(recovery_id: RecoveryId) -> Self {
+        ecdsa::RecoveryId::from_byte(recovery_id.to_byte()).unwrap()
+    }
+}
+
+impl From<ecdsa::RecoveryId> for RecoveryId {
+    fn from(recovery_id: ecdsa::RecoveryId) -> Self {
+        RecoveryId::new(recovery_id.to_byte()).unwrap()
+    }
+}
+
+impl From<ecdsa::VerifyingKey> for Secp256k1Point {
+    fn from(key: ecdsa::VerifyingKey) -> Self {
+        Secp256k1Point::from_sec1(key.to_encoded_point(false).as_bytes()).unwrap()
+    }
+}
+
+impl TryFrom<Secp256k1Point> for ecdsa::VerifyingKey {
+    type Error = Error;
+
+    /// Fails for the point at infinity, which is not a valid key
+    fn try_from(point: Secp256k1Point) -> Result<Self, Self::Error> {
+        let (x, y) = point.to_affine_be();
+
+        let mut sec1 = [0u8; 65];
+        sec1[0] = 0x04;
+        sec1[1..33].copy_from_slice(&x);
+        sec1[33..].copy_from_slice(&y);
+
+        ecdsa::VerifyingKey::from_sec1_bytes(&sec1).map_err(|_| Error::InvalidPoint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::{HasGenerator, HasNeutral};
+    use k256::{AffinePoint, ProjectivePoint};
+
+    #[test]
+    fn recovery_id_bits_match() {
+        for byte in 0..4 {
+            let ours = RecoveryId::new(byte).unwrap();
+            let theirs = ecdsa::RecoveryId::from(ours);
+            assert_eq!(theirs.is_y_odd(), byte & 1 != 0);
+            assert_eq!(theirs.is_x_reduced(), byte & 2 != 0);
+            assert_eq!(RecoveryId::from(theirs), ours);
+        }
+    }
+
+    #[test]
+    fn verifying_key_roundtrip() {
+        let generator = AffinePoint::from(ProjectivePoint::GENERATOR);
+        let key = ecdsa::VerifyingKey::from_affine(generator).unwrap();
+
+        assert_eq!(Secp256k1Point::from(key), *Secp256k1Point::generator());
+        assert_eq!(
+            ecdsa::VerifyingKey::try_from(*Secp256k1Point::generator()).unwrap(),
+            key
+        );
+        assert!(ecdsa::VerifyingKey::try_from(Secp256k1Point::neutral()).is_err());
+    }
+}
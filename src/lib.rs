@@ -1,3 +1,22 @@
+#[cfg(feature = "differential")]
+mod differential;
 pub mod ecdsa;
 pub mod elliptic_curve;
+pub mod error;
+pub mod hashing;
+pub mod hmac;
+#[cfg(feature = "k256-interop")]
+mod k256_interop;
+pub mod limbs;
+pub mod scheme;
 pub mod secp256k1;
+mod self_check;
+#[cfg(test)]
+mod test_fixtures;
+pub mod threshold;
+pub mod transcript;
+pub mod vss;
+pub mod weighted;
+pub mod witness;
+
+pub use self_check::self_check;
@@ -0,0 +1,134 @@
+//! Decomposition of 256-bit values into limbs of a proof system's prime
+//! field, and the range-checked recomposition back, for wiring scalars and
+//! field elements into surrounding circuits.
+
+use ff::PrimeField;
+
+use crate::secp256k1::{Encoding, Secp256k1FieldElement, Secp256k1Scalar};
+
+/// A 256-bit value split into limbs of `limb_bits` bits each, least
+/// significant first, every limb embedded in the field `F`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Limbs<F> {
+    pub limbs: Vec<F>,
+    pub limb_bits: u32,
+}
+
+impl<F: PrimeField> Limbs<F> {
+    /// Splits little-endian bytes into limbs. `limb_bits` must be at most 64
+    /// and at most `F::CAPACITY`, so that no limb wraps around
+    pub fn decompose(bytes_le: &[u8; 32], limb_bits: u32) -> Self {
+        assert!(
+            (1..=64).contains(&limb_bits) && limb_bits <= F::CAPACITY,
+            "limbs must fit in both a u64 and the target field"
+        );
+
+        let bit = |i: u32| (bytes_le[(i / 8) as usize] >> (i % 8)) & 1;
+        let limbs = (0..256u32.div_ceil(limb_bits))
+            .map(|limb| {
+                let start = limb * limb_bits;
+                let end = (start + limb_bits).min(256);
+                let value = (start..end).fold(0u64, |acc, i| acc | (bit(i) as u64) << (i - start));
+                F::from(value)
+            })
+            .collect();
+
+        Limbs { limbs, limb_bits }
+    }
+
+    /// Recombines the limbs into little-endian bytes, or `None` if a limb is
+    /// not below `2^limb_bits` or the value does not fit in 256 bits
+    pub fn recompose(&self) -> Option<[u8; 32]> {
+        let mut bytes = [0u8; 32];
+
+        for (limb_index, limb) in self.limbs.iter().enumerate() {
+            // Peel bits off the limb; it is in range iff nothing is left
+            let mut rest = *limb;
+            for j in 0..self.limb_bits {
+                let odd = bool::from(rest.is_odd());
+                if odd {
+                    rest -= F::ONE;
+                }
+                rest *= F::TWO_INV;
+
+                let i = limb_index * self.limb_bits as usize + j as usize;
+                match (odd, i < 256) {
+                    (true, true) => bytes[i / 8] |= 1 << (i % 8),
+                    (true, false) => return None,
+                    (false, _) => {}
+                }
+            }
+
+            if !bool::from(rest.is_zero()) {
+                return None;
+            }
+        }
+
+        Some(bytes)
+    }
+}
+
+impl Secp256k1Scalar {
+    /// Splits the scalar into limbs of `limb_bits` bits in the field `F`
+    pub fn to_limbs<F: PrimeField>(&self, limb_bits: u32) -> Limbs<F> {
+        Limbs::decompose(&self.to_bytes(Encoding::LittleEndian), limb_bits)
+    }
+
+    /// Recombines limbs into a scalar, rejecting out-of-range limbs and
+    /// values `>= n`
+    pub fn from_limbs<F: PrimeField>(limbs: &Limbs<F>) -> Option<Self> {
+        Secp256k1Scalar::from_bytes_strict(&limbs.recompose()?, Encoding::LittleEndian)
+    }
+}
+
+impl Secp256k1FieldElement {
+    /// Splits the canonical value into limbs of `limb_bits` bits in the
+    /// field `F`
+    pub fn to_limbs<F: PrimeField>(&self, limb_bits: u32) -> Limbs<F> {
+        Limbs::decompose(&self.to_bytes(Encoding::LittleEndian), limb_bits)
+    }
+
+    /// Recombines limbs into a field element, rejecting out-of-range limbs
+    /// and values `>= p`
+    pub fn from_limbs<F: PrimeField>(limbs: &Limbs<F>) -> Option<Self> {
+        Secp256k1FieldElement::from_bytes_strict(&limbs.recompose()?, Encoding::LittleEndian)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::HasGenerator;
+    use crate::secp256k1::Secp256k1Point;
+
+    // Any prime field works as the target; k256's scalar field is at hand
+    type F = k256::Scalar;
+
+    #[test]
+    fn limbs_roundtrip_and_range_check() {
+        let s = -Secp256k1Scalar::ONE;
+        for bits in [8, 29, 31, 64] {
+            let limbs = s.to_limbs::<F>(bits);
+            assert_eq!(limbs.limbs.len(), 256usize.div_ceil(bits as usize));
+            assert_eq!(Secp256k1Scalar::from_limbs(&limbs), Some(s));
+        }
+
+        let x = Secp256k1Point::generator().x();
+        let limbs = x.to_limbs::<F>(29);
+        assert_eq!(limbs.limbs[0], F::from(0x16f81798u64 & ((1 << 29) - 1)));
+        assert_eq!(Secp256k1FieldElement::from_limbs(&limbs), Some(x));
+
+        // A limb at 2^29 is out of range even though the field holds it
+        let mut carry = limbs.clone();
+        carry.limbs[0] = F::from(1u64 << 29);
+        assert_eq!(carry.recompose(), None);
+
+        // Bits above 256 are rejected, as are values above the modulus
+        let mut wide = s.to_limbs::<F>(29);
+        *wide.limbs.last_mut().unwrap() = F::from(1u64 << 24);
+        assert_eq!(wide.recompose(), None);
+        let max = Limbs::<F>::decompose(&[0xff; 32], 64);
+        assert_eq!(max.recompose(), Some([0xff; 32]));
+        assert_eq!(Secp256k1Scalar::from_limbs(&max), None);
+    }
+}
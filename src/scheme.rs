@@ -0,0 +1,89 @@
+//! A single entry point verifying ECDSA signatures from their wire
+//! encodings, for guests that validate signatures in several encodings
+//! (e.g. ERC-4337 user operations) in one loop. Only ECDSA encodings are
+//! supported; the crate has no Schnorr/BIP-340 verifier to dispatch to.
+
+use crate::ecdsa::{RecoverableSignature, Signature, ECDSA};
+use crate::elliptic_curve::FromBeBytes;
+use crate::error::Error;
+use crate::secp256k1::{Secp256k1Point, Secp256k1Scalar};
+
+/// ECDSA signature encodings understood by [`verify`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignatureScheme {
+    /// 20-byte Ethereum address as the public key, 65-byte `r || s || v`
+    /// signature; the key is recovered and its address compared
+    EcdsaRecoverable,
+    /// SEC1 public key, compressed or uncompressed, and 64-byte big-endian
+    /// `r || s` signature
+    EcdsaPlain,
+}
+
+/// Parses the public key and signature in the encodings of `scheme` and
+/// reports whether the signature over `hash` verifies. Malformed encodings
+/// are errors, distinct from a signature that does not verify
+pub fn verify(
+    scheme: SignatureScheme,
+    public_key: &[u8],
+    hash: &[u8; 32],
+    signature: &[u8],
+) -> Result<bool, Error> {
+    match scheme {
+        SignatureScheme::EcdsaRecoverable => {
+            let address: &[u8; 20] = public_key.try_into().map_err(|_| Error::InvalidLength)?;
+            let bytes: &[u8; 65] = signature.try_into().map_err(|_| Error::InvalidLength)?;
+            let signature = RecoverableSignature::from_bytes(bytes)?;
+
+            Ok(ECDSA::verify_with_address(hash, &signature, address))
+        }
+        SignatureScheme::EcdsaPlain => {
+            let public_key = Secp256k1Point::from_sec1(public_key)?;
+            if signature.len() != 64 {
+                return Err(Error::InvalidLength);
+            }
+            let signature = Signature::<Secp256k1Point> {
+                r: Secp256k1Scalar::from_be_bytes(&signature[..32]).ok_or(Error::InvalidScalar)?,
+                s: Secp256k1Scalar::from_be_bytes(&signature[32..]).ok_or(Error::InvalidScalar)?,
+            };
+
+            Ok(ECDSA::verify(hash, &signature, &public_key))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{small_signature, SMALL_HASH};
+
+    #[test]
+    fn dispatches_on_scheme_encodings() {
+        let (public_key, signature) = small_signature();
+        let hash = SMALL_HASH;
+
+        let eth = signature.to_bytes().unwrap();
+        let address = public_key.to_eth_address();
+        let recoverable = SignatureScheme::EcdsaRecoverable;
+        assert_eq!(verify(recoverable, &address, &hash, &eth), Ok(true));
+        assert_eq!(verify(recoverable, &address, &[0; 32], &eth), Ok(false));
+        assert_eq!(
+            verify(recoverable, &public_key.to_sec1_bytes(true), &hash, &eth),
+            Err(Error::InvalidLength)
+        );
+
+        let plain = SignatureScheme::EcdsaPlain;
+        for compress in [true, false] {
+            let sec1 = public_key.to_sec1_bytes(compress);
+            assert_eq!(verify(plain, &sec1, &hash, &eth[..64]), Ok(true));
+            assert_eq!(verify(plain, &sec1, &[0; 32], &eth[..64]), Ok(false));
+        }
+        assert_eq!(
+            verify(plain, &public_key.to_sec1_bytes(true), &hash, &eth),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(
+            verify(plain, &address, &hash, &eth[..64]),
+            Err(Error::InvalidPoint)
+        );
+    }
+}
@@ -1,9 +1,14 @@
+use crate::ecdsa::{RecoverableSignature, RecoveryId, Signature, ECDSA};
 use crate::elliptic_curve::{
-    CheckedAdd, EllipticCurve, FromLeBytes, HasGenerator, HasNeutral, HasSqrt, IsOdd, ToLeBytes,
+    CheckedAdd, EcdsaCurve, FromLeBytes, Group, HasGenerator, HasNeutral, HasSqrt, IsOdd,
+    PointCodec, RecoverableCurve, ToLeBytes,
 };
+use crate::error::Error;
+use crate::hashing::{hash160, Hash256, Keccak256, Sha256};
+pub use k256::U256;
 use k256::{
     elliptic_curve::{bigint::ArrayEncoding, Curve},
-    Secp256k1, U256,
+    Secp256k1,
 };
 use std::{
     fmt::Debug,
@@ -15,44 +20,405 @@ pub use base_field::*;
 pub mod scalar_field;
 pub use scalar_field::*;
 mod constants;
+pub mod poly;
 use constants::*;
 
+/// Serializes least-significant-first 64-bit limbs into little-endian bytes
+#[inline(always)]
+fn words_to_le_bytes(words: [u64; 4]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (i, word) in words.iter().enumerate() {
+        bytes[8 * i..8 * i + 8].copy_from_slice(&word.to_le_bytes());
+    }
+    bytes
+}
+
+/// Splits little-endian bytes into least-significant-first 64-bit limbs
+#[inline(always)]
+fn le_bytes_to_words(bytes: &[u8; 32]) -> [u64; 4] {
+    let mut words = [0u64; 4];
+    for (i, word) in words.iter_mut().enumerate() {
+        *word = u64::from_le_bytes(bytes[8 * i..8 * i + 8].try_into().unwrap());
+    }
+    words
+}
+
+const ONE: [u8; 32] = {
+    let mut x: [u8; 32] = [0; 32];
+    x[0] = 1;
+    x
+};
+
+/// Runs the comb intrinsic, setting `arg_2.point` to
+/// `arg_1.scalar * arg_1.point + arg_2.scalar * arg_2.point`
+#[inline(always)]
+fn comb(arg_1: &intrinsics::Secp256k1Comb, arg_2: &mut intrinsics::Secp256k1Comb) {
+    #[cfg(feature = "differential")]
+    let (s2, p2) = (Secp256k1Scalar(arg_2.scalar), Secp256k1Point(arg_2.point));
+
+    intrinsics::comb_secp256k1(arg_1, arg_2);
+
+    #[cfg(feature = "differential")]
+    crate::differential::check_lin_comb(
+        &Secp256k1Scalar(arg_1.scalar),
+        &Secp256k1Point(arg_1.point),
+        &s2,
+        &p2,
+        &Secp256k1Point(arg_2.point),
+    );
+}
+
 impl Mul<Secp256k1Scalar> for Secp256k1Point {
     type Output = Secp256k1Point;
 
     fn mul(self, rhs: Secp256k1Scalar) -> Self::Output {
         let mut copied = self;
         intrinsics::smul_secp256k1(&rhs.0, &mut copied.0);
+
+        #[cfg(feature = "differential")]
+        crate::differential::check_mul(&self, &rhs, &copied);
+
         copied
     }
 }
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
 pub struct Secp256k1Point(intrinsics::Secp256k1Point);
 
+impl Debug for Secp256k1Point {
+    /// Prints the compressed SEC1 encoding as hex
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Secp256k1Point({})",
+            hex::encode(self.to_sec1_bytes(true))
+        )
+    }
+}
+
 impl Secp256k1Point {
+    #[deprecated(note = "use `from_bytes` with `Encoding::LittleEndian`")]
     pub fn create(x_bytes: [u8; 32], y_bytes: [u8; 32]) -> Option<Self> {
-        let x = Secp256k1FieldElement::from_repr(&x_bytes)?;
-        let y = Secp256k1FieldElement::from_repr(&y_bytes)?;
+        Self::from_coordinates(&x_bytes, &y_bytes, Encoding::LittleEndian)
+    }
 
-        let lhs = y * &y;
-        let rhs = x * &x * &x + 7 as u64;
+    /// Decodes a point from its 64-byte `x || y` encoding, each coordinate
+    /// in the given byte order. Fails if the coordinates are out of range or
+    /// off the curve. See [`Self::from_sec1`] for SEC1
+    pub fn from_bytes(bytes: &[u8; 64], encoding: Encoding) -> Result<Self, Error> {
+        let x: [u8; 32] = bytes[..32].try_into().unwrap();
+        let y: [u8; 32] = bytes[32..].try_into().unwrap();
+        Self::from_coordinates(&x, &y, encoding).ok_or(Error::InvalidPoint)
+    }
 
-        let satisfies_equation = lhs.0.normalize() == rhs.0.normalize();
+    /// Encodes the point as the 64-byte `x || y`, each coordinate in the
+    /// given byte order. See [`Self::to_sec1_bytes`] for SEC1
+    pub fn to_bytes(&self, encoding: Encoding) -> [u8; 64] {
+        let (x, y) = match encoding {
+            Encoding::LittleEndian => (self.0.x, self.0.y),
+            Encoding::BigEndian => self.to_affine_be(),
+        };
 
-        if satisfies_equation {
-            Some(Secp256k1Point(intrinsics::Secp256k1Point {
-                x: x_bytes,
-                y: y_bytes,
-            }))
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&x);
+        bytes[32..].copy_from_slice(&y);
+        bytes
+    }
+
+    fn from_coordinates(
+        x_bytes: &[u8; 32],
+        y_bytes: &[u8; 32],
+        encoding: Encoding,
+    ) -> Option<Self> {
+        let x = Secp256k1FieldElement::from_bytes_strict(x_bytes, encoding)?;
+        let y = Secp256k1FieldElement::from_bytes_strict(y_bytes, encoding)?;
+
+        Self::try_from((x, y)).ok()
+    }
+
+    /// Wraps affine coordinates already known to satisfy the curve equation
+    #[inline(always)]
+    fn from_affine(x: &Secp256k1FieldElement, y: &Secp256k1FieldElement) -> Self {
+        Secp256k1Point(intrinsics::Secp256k1Point {
+            x: x.to_bytes(Encoding::LittleEndian),
+            y: y.to_bytes(Encoding::LittleEndian),
+        })
+    }
+
+    #[inline(always)]
+    fn satisfies_curve_equation(x: &Secp256k1FieldElement, y: &Secp256k1FieldElement) -> bool {
+        *y * *y == *x * *x * *x + 7u64
+    }
+
+    /// Whether the coordinates are canonical and satisfy `y^2 = x^3 + 7`.
+    /// Every constructor checks this except the `unchecked` ones; the point
+    /// at infinity never satisfies it
+    pub fn is_on_curve(&self) -> bool {
+        let x = Secp256k1FieldElement::from_bytes_strict(&self.0.x, Encoding::LittleEndian);
+        let y = Secp256k1FieldElement::from_bytes_strict(&self.0.y, Encoding::LittleEndian);
+
+        match (x, y) {
+            (Some(x), Some(y)) => Self::satisfies_curve_equation(&x, &y),
+            _ => false,
+        }
+    }
+
+    /// Builds a point from little-endian coordinates without checking the
+    /// curve equation. The caller must guarantee the coordinates describe a
+    /// valid point, e.g. because the witness was validated host-side
+    #[cfg(feature = "unchecked")]
+    #[inline(always)]
+    pub fn from_repr_unchecked(x_bytes: [u8; 32], y_bytes: [u8; 32]) -> Self {
+        Secp256k1Point(intrinsics::Secp256k1Point {
+            x: x_bytes,
+            y: y_bytes,
+        })
+    }
+
+    /// Parses a SEC1-encoded point, either compressed (33 bytes) or
+    /// uncompressed (65 bytes). The single zero byte written by
+    /// [`Self::to_sec1_bytes`] parses as the point at infinity, which the
+    /// verifiers reject as a public key
+    pub fn from_sec1(bytes: &[u8]) -> Result<Self, Error> {
+        match (bytes.first(), bytes.len()) {
+            (Some(0x00), 1) => Ok(Self::neutral()),
+            (Some(tag @ (0x02 | 0x03)), 33) => {
+                let x = Secp256k1FieldElement::from_bytes_strict(
+                    bytes[1..].try_into().unwrap(),
+                    Encoding::BigEndian,
+                )
+                .ok_or(Error::InvalidPoint)?;
+                Self::decompress(&x.to_bytes(Encoding::LittleEndian), *tag == 0x03)
+                    .ok_or(Error::InvalidPoint)
+            }
+            (Some(0x04), 65) => {
+                let x: [u8; 32] = bytes[1..33].try_into().unwrap();
+                let y: [u8; 32] = bytes[33..].try_into().unwrap();
+                Self::from_coordinates(&x, &y, Encoding::BigEndian).ok_or(Error::InvalidPoint)
+            }
+            _ => Err(Error::InvalidPoint),
+        }
+    }
+
+    /// Adds `tweak * G` to the point, as libsecp256k1's
+    /// `ec_pubkey_tweak_add`. Fails if the result is the point at infinity
+    pub fn tweak_add(&self, tweak: &Secp256k1Scalar) -> Option<Self> {
+        let tweaked = Self::lin_comb(&Secp256k1Scalar::ONE, self, tweak, Self::generator());
+
+        if tweaked == Self::neutral() {
+            None
         } else {
+            Some(tweaked)
+        }
+    }
+
+    /// Multiplies the point by `tweak`, as libsecp256k1's
+    /// `ec_pubkey_tweak_mul`. Fails if the tweak is zero
+    pub fn tweak_mul(&self, tweak: &Secp256k1Scalar) -> Option<Self> {
+        if tweak.is_zero() {
             None
+        } else {
+            Some(*self * *tweak)
+        }
+    }
+
+    /// Pay-to-contract tweak committing `base` to `data`:
+    /// `SHA-256(compressed(base) || data)` reduced modulo n
+    pub fn commitment_tweak(base: &Secp256k1Point, data: &[u8]) -> Secp256k1Scalar {
+        let mut hasher = Sha256::new();
+        hasher.update(&base.to_sec1_bytes(true));
+        hasher.update(data);
+        Self::reduce_hash(&hasher.finalize())
+    }
+
+    /// Checks that `derived = base + H(base || data) * G`, the
+    /// pay-to-contract and sign-to-contract commitment, with one `lin_comb`
+    pub fn verify_commitment(base: &Secp256k1Point, data: &[u8], derived: &Secp256k1Point) -> bool {
+        base.tweak_add(&Self::commitment_tweak(base, data)) == Some(*derived)
+    }
+
+    /// Derives a point from `seed`, reproducibly, by hashing it under the
+    /// tag `domain` to an x-coordinate and lifting it to the point with even
+    /// y, retrying with a counter until the hash lands on the curve. Nobody
+    /// knows the discrete log of the result
+    pub fn from_seed(seed: [u8; 32], domain: &str) -> Self {
+        (0u32..)
+            .find_map(|counter| {
+                let mut hasher = Sha256::new_tagged(domain.as_bytes());
+                hasher.update(&seed);
+                hasher.update(&counter.to_be_bytes());
+                let x = Secp256k1FieldElement::from_bytes_strict(
+                    &hasher.finalize(),
+                    Encoding::BigEndian,
+                )?;
+                Self::decompress(&x.to_bytes(Encoding::LittleEndian), false)
+            })
+            .unwrap()
+    }
+
+    /// Encodes the point in SEC1 format, compressed (33 bytes) or
+    /// uncompressed (65 bytes). The point at infinity encodes as a single
+    /// zero byte
+    pub fn to_sec1_bytes(&self, compress: bool) -> Vec<u8> {
+        if *self == Self::neutral() {
+            return vec![0x00];
         }
+
+        let (x, y) = self.to_affine_be();
+        let mut bytes = Vec::with_capacity(65);
+        if compress {
+            bytes.push(if self.y_is_odd() { 0x03 } else { 0x02 });
+            bytes.extend_from_slice(&x);
+        } else {
+            bytes.push(0x04);
+            bytes.extend_from_slice(&x);
+            bytes.extend_from_slice(&y);
+        }
+        bytes
     }
 
+    #[deprecated(note = "use `to_bytes` with `Encoding::LittleEndian`")]
     pub fn to_repr(&self) -> ([u8; 32], [u8; 32]) {
         (self.0.x, self.0.y)
     }
+
+    /// Returns the affine coordinates as big-endian integers, the byte order
+    /// used by SEC1, Bitcoin and Ethereum serializations
+    #[inline(always)]
+    pub fn to_affine_be(&self) -> ([u8; 32], [u8; 32]) {
+        let (mut x, mut y) = (self.0.x, self.0.y);
+        x.reverse();
+        y.reverse();
+        (x, y)
+    }
+
+    #[inline(always)]
+    pub fn to_affine_u256(&self) -> (U256, U256) {
+        (
+            U256::from_le_slice(&self.0.x),
+            U256::from_le_slice(&self.0.y),
+        )
+    }
+
+    #[inline(always)]
+    pub fn x(&self) -> Secp256k1FieldElement {
+        Secp256k1FieldElement::from_bytes_reduced(&self.0.x, Encoding::LittleEndian)
+    }
+
+    #[inline(always)]
+    pub fn y(&self) -> Secp256k1FieldElement {
+        Secp256k1FieldElement::from_bytes_reduced(&self.0.y, Encoding::LittleEndian)
+    }
+
+    /// Returns `x mod n` together with whether the reduction wrapped, i.e.
+    /// whether `x >= n`. The flag is the x-reduced bit of a recovery id
+    #[inline(always)]
+    pub fn x_reduced(&self) -> (Secp256k1Scalar, bool) {
+        let x = U256::from_le_slice(&self.0.x);
+        (
+            Secp256k1Scalar::from_bytes_reduced(&self.0.x, Encoding::LittleEndian),
+            x >= Secp256k1::ORDER,
+        )
+    }
+
+    /// Parity of the y coordinate, read directly from its canonical
+    /// little-endian representation
+    #[inline(always)]
+    pub fn y_is_odd(&self) -> bool {
+        (self.0.y[0] & 1) != 0
+    }
+
+    /// Returns the Ethereum address of the point as a public key: the last
+    /// 20 bytes of the Keccak-256 hash of its big-endian `x || y`
+    pub fn to_eth_address(&self) -> [u8; 20] {
+        let (x, y) = self.to_affine_be();
+        let mut hasher = Keccak256::new();
+        hasher.update(&x);
+        hasher.update(&y);
+
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&hasher.finalize()[12..]);
+        address
+    }
+
+    /// Returns the Bitcoin public key hash of the point, `HASH160` of its
+    /// SEC1 encoding, compressed or uncompressed: the 20 bytes a P2PKH or
+    /// P2WPKH output commits to. Base58Check or bech32 encoding into an
+    /// address string is left to the caller
+    pub fn to_pubkey_hash(&self, compress: bool) -> [u8; 20] {
+        let (x, y) = self.to_affine_be();
+        let mut sec1 = [0u8; 65];
+        sec1[1..33].copy_from_slice(&x);
+
+        let len = if compress {
+            sec1[0] = if self.y_is_odd() { 0x03 } else { 0x02 };
+            33
+        } else {
+            sec1[0] = 0x04;
+            sec1[33..].copy_from_slice(&y);
+            65
+        };
+        hash160(&sec1[..len])
+    }
+
+    /// Multiplies the point by a small integer with double-and-add, folding
+    /// each bit of `k` below the leading one in with a single `comb` as
+    /// `acc = 2 * acc + bit * self`. No 256-bit scalar is built and `smul` is
+    /// never called, but the cost grows with the bit length of `k`, so large
+    /// multipliers are cheaper through [`Mul`]
+    pub fn mul_small(&self, k: u64) -> Self {
+        if k == 0 {
+            return Self::neutral();
+        }
+
+        let mut two = Secp256k1Scalar::ZERO;
+        two.0.value[0] = 2;
+
+        let mut acc = *self;
+        for i in (0..k.ilog2()).rev() {
+            let bit = if (k >> i) & 1 == 1 {
+                &Secp256k1Scalar::ONE
+            } else {
+                &Secp256k1Scalar::ZERO
+            };
+            acc.comb_assign(&two, self, bit);
+        }
+        acc
+    }
+
+    /// Applies the endomorphism `(x, y) -> (beta * x, y)`, equal to
+    /// multiplying by [`Secp256k1Scalar::LAMBDA`] at the cost of one field
+    /// multiplication
+    pub fn mul_by_lambda(&self) -> Self {
+        let x = self.x() * Secp256k1FieldElement::from_bytes_reduced(&BETA, Encoding::LittleEndian);
+        Secp256k1Point(intrinsics::Secp256k1Point {
+            x: x.to_bytes(Encoding::LittleEndian),
+            y: self.0.y,
+        })
+    }
+
+    /// Sets `self` to `self_scalar * self + other_scalar * other`, mapping
+    /// 1:1 onto the in-place comb intrinsic so chained combinations skip the
+    /// copy [`Group::lin_comb`] makes
+    #[inline(always)]
+    pub fn comb_assign(
+        &mut self,
+        self_scalar: &Secp256k1Scalar,
+        other: &Secp256k1Point,
+        other_scalar: &Secp256k1Scalar,
+    ) {
+        let arg_1 = intrinsics::Secp256k1Comb {
+            point: other.0,
+            scalar: other_scalar.0,
+        };
+        let mut arg_2 = intrinsics::Secp256k1Comb {
+            point: self.0,
+            scalar: self_scalar.0,
+        };
+        comb(&arg_1, &mut arg_2);
+
+        self.0 = arg_2.point;
+    }
 }
 
 impl Add for Secp256k1Point {
@@ -60,12 +426,6 @@ impl Add for Secp256k1Point {
 
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        const ONE: [u8; 32] = {
-            let mut x: [u8; 32] = [0; 32];
-            x[0] = 1;
-            x
-        };
-
         let arg_1 = intrinsics::Secp256k1Comb {
             point: self.0,
             scalar: intrinsics::Secp256k1Scalar { value: ONE },
@@ -76,7 +436,7 @@ impl Add for Secp256k1Point {
             scalar: intrinsics::Secp256k1Scalar { value: ONE },
         };
 
-        intrinsics::comb_secp256k1(&arg_1, &mut arg_2);
+        comb(&arg_1, &mut arg_2);
         Secp256k1Point(arg_2.point)
     }
 }
@@ -103,14 +463,16 @@ impl HasGenerator for Secp256k1Point {
 impl TryFrom<(Secp256k1FieldElement, Secp256k1FieldElement)> for Secp256k1Point {
     type Error = ();
 
+    /// Fails if the coordinates do not satisfy the curve equation
     #[inline(always)]
     fn try_from(
-        value: (Secp256k1FieldElement, Secp256k1FieldElement),
+        (x, y): (Secp256k1FieldElement, Secp256k1FieldElement),
     ) -> Result<Self, Self::Error> {
-        let x = value.0.to_repr();
-        let y = value.1.to_repr();
-
-        Ok(Secp256k1Point(intrinsics::Secp256k1Point { x, y }))
+        if Secp256k1Point::satisfies_curve_equation(&x, &y) {
+            Ok(Secp256k1Point::from_affine(&x, &y))
+        } else {
+            Err(())
+        }
     }
 }
 
@@ -136,33 +498,18 @@ impl ToLeBytes for U256 {
     fn to_le_bytes(&self) -> Vec<u8> {
         self.to_le_byte_array().to_vec()
     }
-}
-
-impl EllipticCurve for Secp256k1Point {
-    type Scalar = Secp256k1Scalar;
-    type Uint = U256;
 
     #[inline(always)]
-    fn get_x_coord(&self) -> Self::Scalar {
-        scalar_reduce(&self.0.x)
+    fn write_le_bytes(&self, out: &mut [u8]) {
+        out.copy_from_slice(&self.to_le_byte_array());
     }
+}
 
-    #[inline(always)]
-    fn reduce_hash(hash: &[u8; 32]) -> Self::Scalar {
-        let mut r: intrinsics::Secp256k1Scalar = Default::default();
-
-        // Read big-endian 32-bit words from the input
-        let mut b32_copied = *hash;
-        b32_copied.reverse();
-        r.value = b32_copied;
+impl Group for Secp256k1Point {
+    type Scalar = Secp256k1Scalar;
+    type ScalarBytes = [u8; 32];
 
-        scalar_reduce(&r.value)
-    }
-
-    #[inline(always)]
-    fn is_high(s: &Self::Scalar) -> bool {
-        s.is_high()
-    }
+    const SCALAR_BYTES: usize = 32;
 
     #[inline(always)]
     fn lin_comb(s1: &Self::Scalar, p1: &Self, s2: &Self::Scalar, p2: &Self) -> Self {
@@ -174,11 +521,43 @@ impl EllipticCurve for Secp256k1Point {
             point: p2.0,
             scalar: s2.0,
         };
-        intrinsics::comb_secp256k1(&arg_1, &mut arg_2);
+        comb(&arg_1, &mut arg_2);
 
         Secp256k1Point(arg_2.point)
     }
 
+    #[inline(always)]
+    fn lin_comb_iter<'a>(mut terms: impl Iterator<Item = (&'a Self::Scalar, &'a Self)>) -> Self {
+        let Some((s, p)) = terms.next() else {
+            return Self::neutral();
+        };
+
+        // The accumulator stays in the mutable argument, each further term is
+        // folded in with a unit scalar on the accumulated point
+        let mut acc = intrinsics::Secp256k1Comb {
+            point: p.0,
+            scalar: s.0,
+        };
+        for (s, p) in terms {
+            let term = intrinsics::Secp256k1Comb {
+                point: p.0,
+                scalar: s.0,
+            };
+            comb(&term, &mut acc);
+            acc.scalar = intrinsics::Secp256k1Scalar { value: ONE };
+        }
+
+        if acc.scalar.value == ONE {
+            Secp256k1Point(acc.point)
+        } else {
+            Secp256k1Point(acc.point) * Secp256k1Scalar(acc.scalar)
+        }
+    }
+}
+
+impl PointCodec for Secp256k1Point {
+    type FieldBytes = [u8; 32];
+
     #[inline(always)]
     fn decompress(bytes_le: &[u8], is_y_odd: bool) -> Option<Self> {
         let fx = Secp256k1FieldElement::from_le_bytes(bytes_le)?;
@@ -188,20 +567,140 @@ impl EllipticCurve for Secp256k1Point {
 
         let y = if y_r.is_odd() != is_y_odd { -y_r } else { y_r };
 
-        Self::try_from((fx, y)).ok()
+        // The square root already guarantees the curve equation
+        Some(Self::from_affine(&fx, &y))
+    }
+}
+
+impl EcdsaCurve for Secp256k1Point {
+    #[inline(always)]
+    fn get_x_coord(&self) -> Self::Scalar {
+        Secp256k1Scalar::from_bytes_reduced(&self.0.x, Encoding::LittleEndian)
+    }
+
+    #[inline(always)]
+    fn reduce_hash(hash: &[u8; 32]) -> Self::Scalar {
+        // Hashes are big-endian, the intrinsics expect little-endian scalars
+        Secp256k1Scalar::from_bytes_reduced(hash, Encoding::BigEndian)
+    }
+
+    #[inline(always)]
+    fn reduce_be_bytes(bytes: &Self::ScalarBytes) -> Self::Scalar {
+        Secp256k1Scalar::from_bytes_reduced(bytes, Encoding::BigEndian)
+    }
+
+    #[inline(always)]
+    fn is_high(s: &Self::Scalar) -> bool {
+        s.is_high()
+    }
+
+    #[inline(always)]
+    fn is_valid_public_key(&self) -> bool {
+        *self != Self::neutral()
     }
 
+    const ORDER_BITS: usize = 256;
+}
+
+impl RecoverableCurve for Secp256k1Point {
+    type Uint = U256;
+
     const ORDER: Self::Uint = Secp256k1::ORDER;
 }
 
+impl RecoverableSignature<Secp256k1Point> {
+    /// Recovers the Ethereum address of the key that produced the signature
+    /// over `hash`
+    pub fn recover_address(&self, hash: &[u8; 32]) -> Result<[u8; 20], Error> {
+        let public_key = self.recover(hash)?;
+        if public_key == Secp256k1Point::neutral() {
+            return Err(Error::InvalidPoint);
+        }
+
+        Ok(public_key.to_eth_address())
+    }
+
+    /// Recovers the Bitcoin public key hash, see
+    /// [`Secp256k1Point::to_pubkey_hash`], of the key that produced the
+    /// signature over `hash`
+    pub fn recover_pubkey_hash(&self, hash: &[u8; 32], compress: bool) -> Result<[u8; 20], Error> {
+        let public_key = self.recover(hash)?;
+        if public_key == Secp256k1Point::neutral() {
+            return Err(Error::InvalidPoint);
+        }
+
+        Ok(public_key.to_pubkey_hash(compress))
+    }
+}
+
+impl ECDSA<Secp256k1Point> {
+    /// Checks that the key with Ethereum address `expected_address` signed
+    /// `hash`, by recovering the key and comparing its address. As
+    /// [`ECDSA::verify`], zero components and high-s signatures are rejected
+    pub fn verify_with_address(
+        hash: &[u8; 32],
+        signature: &RecoverableSignature<Secp256k1Point>,
+        expected_address: &[u8; 20],
+    ) -> bool {
+        let Signature { r, s } = signature.signature;
+        if r.is_zero() || s.is_zero() || s.is_high() {
+            return false;
+        }
+
+        signature
+            .recover_address(hash)
+            .is_ok_and(|address| address == *expected_address)
+    }
+
+    /// Checks that the key with Bitcoin public key hash `expected_hash`,
+    /// over its compressed or uncompressed SEC1 encoding as `compress`
+    /// says, signed `hash`. As [`ECDSA::verify_with_address`], zero
+    /// components and high-s signatures are rejected
+    pub fn verify_with_pubkey_hash(
+        hash: &[u8; 32],
+        signature: &RecoverableSignature<Secp256k1Point>,
+        expected_hash: &[u8; 20],
+        compress: bool,
+    ) -> bool {
+        if signature.signature.s.is_high() {
+            return false;
+        }
+
+        signature
+            .recover_pubkey_hash(hash, compress)
+            .is_ok_and(|pubkey_hash| pubkey_hash == *expected_hash)
+    }
+
+    /// Recovers the public key and returns it SEC1-compressed. The encoding
+    /// is read straight off the intrinsic's canonical coordinates, without
+    /// going through field elements
+    pub fn recover_compressed(
+        hash: &[u8; 32],
+        signature: &Signature<Secp256k1Point>,
+        recovery_id: &RecoveryId,
+    ) -> Result<[u8; 33], Error> {
+        let point = Self::recover_key(hash, signature, recovery_id)?;
+        if point == Secp256k1Point::neutral() {
+            return Err(Error::InvalidPoint);
+        }
+
+        let mut bytes = [0u8; 33];
+        bytes[0] = if point.y_is_odd() { 0x03 } else { 0x02 };
+        bytes[1..].copy_from_slice(&point.0.x);
+        bytes[1..].reverse();
+        Ok(bytes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::elliptic_curve::FromLeBytes;
     use crate::elliptic_curve::MultiplicativeInverse;
+    use crate::elliptic_curve::{FromBeBytes, FromLeBytes, ToBeBytes};
 
     use super::Secp256k1Point as P;
     use super::Secp256k1Scalar as S;
     use super::*;
+    use crate::test_fixtures::{known_answer, KnownAnswer};
 
     #[test]
     fn add_neutral_to_generator() {
@@ -233,4 +732,710 @@ mod tests {
 
         assert_eq!(two * two_inv, one);
     }
+
+    #[test]
+    fn reduce_hash_reads_big_endian() {
+        let mut hash: [u8; 32] = [0; 32];
+        hash[31] = 2;
+
+        let mut two: [u8; 32] = [0; 32];
+        two[0] = 2;
+
+        assert_eq!(P::reduce_hash(&hash), S::from_le_bytes(&two).unwrap());
+    }
+
+    #[test]
+    fn lin_comb_iter_matches_pairwise_lin_comb() {
+        let mut two: [u8; 32] = [0; 32];
+        two[0] = 2;
+        let mut three: [u8; 32] = [0; 32];
+        three[0] = 3;
+
+        let two = S::from_le_bytes(&two).unwrap();
+        let three = S::from_le_bytes(&three).unwrap();
+        let g = *P::generator();
+        let g2 = g * two;
+
+        let expected = P::lin_comb(&two, &g, &three, &g2) + g2 * two;
+        let terms = [(two, g), (three, g2), (two, g2)];
+
+        assert_eq!(
+            P::lin_comb_iter(terms.iter().map(|(s, p)| (s, p))),
+            expected
+        );
+        assert_eq!(P::lin_comb_iter(terms[..1].iter().map(|(s, p)| (s, p))), g2);
+        assert_eq!(P::lin_comb_iter(std::iter::empty()), P::neutral());
+    }
+
+    #[test]
+    fn eth_signature_bytes_roundtrip() {
+        let mut bytes = [0u8; 65];
+        bytes[31] = 1;
+        bytes[63] = 2;
+        bytes[64] = 28;
+
+        let (signature, recid) = Signature::<P>::from_eth_bytes(&bytes).unwrap();
+        assert_eq!(recid, RecoveryId::new(1).unwrap());
+        assert_eq!(signature.to_eth_bytes(&recid).unwrap(), bytes);
+
+        bytes[64] = 38;
+        assert_eq!(Signature::<P>::from_eth_bytes(&bytes).unwrap().1, recid);
+
+        bytes[64] = 2;
+        assert!(Signature::<P>::from_eth_bytes(&bytes).is_err());
+
+        assert_eq!(RecoveryId::new(3).map(|id| id.to_byte()), Some(3));
+        assert_eq!(RecoveryId::new(4), None);
+        let reduced = RecoveryId::new(2).unwrap();
+        assert_eq!(
+            signature.to_eth_bytes(&reduced),
+            Err(Error::InvalidRecoveryId)
+        );
+    }
+
+    #[test]
+    fn field_element_comparison_is_canonical() {
+        let zero = Secp256k1FieldElement::default();
+        let one = zero + 1;
+        let minus_one = -one;
+
+        assert!(zero.is_zero());
+        assert!((one + minus_one).is_zero());
+        assert_eq!(one + minus_one, zero);
+        assert!(zero < one);
+        assert!(one < minus_one);
+    }
+
+    #[test]
+    fn from_bytes_reduced_wraps_out_of_range_values() {
+        let max = [0xff; 32];
+
+        assert!(Secp256k1FieldElement::from_bytes_strict(&max, Encoding::LittleEndian).is_none());
+        // 2^256 - 1 = p + 0x1000003d0
+        let mut expected: [u8; 32] = [0; 32];
+        expected[..5].copy_from_slice(&[0xd0, 0x03, 0x00, 0x00, 0x01]);
+        assert_eq!(
+            Secp256k1FieldElement::from_bytes_reduced(&max, Encoding::LittleEndian)
+                .to_bytes(Encoding::LittleEndian),
+            expected
+        );
+
+        assert!(S::from_bytes_strict(&max, Encoding::LittleEndian).is_none());
+
+        let above: [u8; 32] = Secp256k1::ORDER
+            .wrapping_add(&U256::from_u64(5))
+            .to_be_byte_array()
+            .into();
+        assert_eq!(
+            S::from_bytes_reduced(&above, Encoding::BigEndian),
+            S::from_words([5, 0, 0, 0]).unwrap()
+        );
+        assert!(S::from_bytes_strict(&above, Encoding::BigEndian).is_none());
+    }
+
+    #[test]
+    fn coordinate_accessors_match_repr() {
+        let g = *P::generator();
+        let xy = g.to_bytes(Encoding::LittleEndian);
+
+        assert_eq!(g.x().to_bytes(Encoding::LittleEndian), xy[..32]);
+        assert_eq!(g.y().to_bytes(Encoding::LittleEndian), xy[32..]);
+        assert_eq!(g.y_is_odd(), g.y().is_odd());
+        assert_ne!((-g.y()).is_odd(), g.y_is_odd());
+    }
+
+    #[test]
+    fn word_and_u256_conversions_roundtrip() {
+        let words = [1, 2, 3, 4];
+
+        let fe = Secp256k1FieldElement::from_words(words).unwrap();
+        assert_eq!(fe.to_words(), words);
+        assert_eq!(Secp256k1FieldElement::try_from(U256::from(fe)), Ok(fe));
+        assert_eq!(
+            Secp256k1FieldElement::try_from(MODULUS_U256),
+            Err(Error::InvalidFieldElement)
+        );
+
+        let s = S::from_words(words).unwrap();
+        assert_eq!(s.to_words(), words);
+        assert_eq!(S::try_from(U256::from(s)), Ok(s));
+        assert_eq!(S::try_from(ORDER_U256), Err(Error::InvalidScalar));
+
+        assert!(S::from_words([u64::MAX; 4]).is_none());
+        assert_eq!(reduce_u256_mod_n(Secp256k1::ORDER), S::default());
+        assert!(Secp256k1FieldElement::from_words([u64::MAX; 4]).is_none());
+        assert_eq!(
+            Secp256k1FieldElement::from_words([7, 0, 0, 0]),
+            Some(Secp256k1FieldElement::ZERO + 7)
+        );
+        assert_eq!(P::generator().x().to_words()[3], 0x79be667ef9dcbbac);
+    }
+
+    #[test]
+    fn parse_sec1_generator() {
+        let g = *P::generator();
+        let (x, y) = g.to_affine_be();
+
+        let mut compressed = vec![0x02];
+        compressed.extend_from_slice(&x);
+        let mut uncompressed = vec![0x04];
+        uncompressed.extend_from_slice(&x);
+        uncompressed.extend_from_slice(&y);
+
+        assert_eq!(P::from_sec1(&compressed), Ok(g));
+        assert_eq!(P::from_sec1(&uncompressed), Ok(g));
+
+        assert_eq!(g.to_sec1_bytes(true), compressed);
+        assert_eq!(g.to_sec1_bytes(false), uncompressed);
+        assert_eq!(P::neutral().to_sec1_bytes(true), vec![0x00]);
+
+        compressed[0] = 0x03;
+        assert_ne!(P::from_sec1(&compressed).unwrap(), g);
+        assert!(P::from_sec1(&compressed[1..]).is_err());
+        assert!(P::from_sec1(&[0x00, 0x00]).is_err());
+    }
+
+    #[test]
+    fn sec1_round_trips() {
+        let g = *P::generator();
+        for p in [P::neutral(), g, g.mul_small(2), g.mul_small(3)] {
+            for compress in [true, false] {
+                assert_eq!(P::from_sec1(&p.to_sec1_bytes(compress)), Ok(p));
+            }
+        }
+    }
+
+    #[test]
+    fn constructors_reject_off_curve_points() {
+        let g = *P::generator();
+        let y = g.y() + 1u64;
+        assert_eq!(P::try_from((g.x(), y)), Err(()));
+        assert_eq!(P::try_from((g.x(), g.y())), Ok(g));
+
+        let (x_be, _) = g.to_affine_be();
+        let y_be = y.to_bytes(Encoding::BigEndian);
+        let mut sec1 = vec![0x04];
+        sec1.extend_from_slice(&x_be);
+        sec1.extend_from_slice(&y_be);
+        assert_eq!(P::from_sec1(&sec1), Err(Error::InvalidPoint));
+        assert_eq!(
+            P::from_bytes(
+                &[x_be, y_be].concat().try_into().unwrap(),
+                Encoding::BigEndian
+            ),
+            Err(Error::InvalidPoint)
+        );
+    }
+
+    #[test]
+    fn big_endian_bytes_roundtrip() {
+        let mut be: [u8; 32] = [0; 32];
+        be[31] = 2;
+        be[0] = 1;
+
+        let s = S::from_be_bytes(&be).unwrap();
+        assert_eq!(s.to_be_bytes(), be);
+        assert_eq!(s.to_le_bytes().into_iter().rev().collect::<Vec<_>>(), be);
+
+        let fe = Secp256k1FieldElement::from_be_bytes(&be).unwrap();
+        assert_eq!(fe.to_be_bytes(), be);
+
+        assert!(S::from_be_bytes(&[0xff; 32]).is_none());
+        assert!(Secp256k1FieldElement::from_be_bytes(&[0xff; 32]).is_none());
+        assert!(S::from_be_bytes(&be[1..]).is_none());
+    }
+
+    #[test]
+    fn wnaf_digits_recompose_to_scalar() {
+        let s = S::from_words([0x0123456789abcdef, u64::MAX, 42, 0xfedcba9876543210]).unwrap();
+        let digit = |d: i8| {
+            let abs = S::from_words([d.unsigned_abs() as u64, 0, 0, 0]).unwrap();
+            if d < 0 {
+                -abs
+            } else {
+                abs
+            }
+        };
+        let two = digit(2);
+
+        for w in 2..=8 {
+            let naf = s.to_wnaf(w);
+            let recomposed = naf
+                .iter()
+                .rev()
+                .fold(S::default(), |acc, d| acc * two + digit(*d));
+            assert_eq!(recomposed, s);
+
+            for (i, d) in naf.iter().enumerate().filter(|(_, d)| **d != 0) {
+                assert!(d % 2 != 0 && (d.unsigned_abs() as i32) < 1 << (w - 1));
+                assert!(naf[i + 1..].iter().take(w - 1).all(|d| *d == 0));
+            }
+        }
+
+        let bits = s.to_le_bits();
+        assert!(bits[0] && bits[1] && bits[2] && !bits[4]);
+        assert_eq!(s.to_naf(), s.to_wnaf(2));
+    }
+
+    #[test]
+    fn sqrt_ratio_matches_sqrt_of_quotient() {
+        let u = Secp256k1FieldElement::default() + 9;
+        let v = Secp256k1FieldElement::default() + 4;
+
+        // 9 / 4 = (3 / 2)^2
+        let root = Secp256k1FieldElement::sqrt_ratio(&u, &v).unwrap();
+        assert_eq!(root * root * v, u);
+
+        // 3 is not a square modulo p, and neither is 3 / 4
+        let three = Secp256k1FieldElement::default() + 3;
+        assert!(three.sqrt().is_none());
+        assert!(Secp256k1FieldElement::sqrt_ratio(&three, &v).is_none());
+        assert!(Secp256k1FieldElement::sqrt_ratio(&u, &Secp256k1FieldElement::default()).is_none());
+    }
+
+    #[test]
+    fn eth_address_of_generator() {
+        // Address of the secret key 1
+        assert_eq!(
+            hex::encode(P::generator().to_eth_address()),
+            "7e5f4552091a69125d5dfcb7b8c2659029395bdf"
+        );
+    }
+
+    #[test]
+    fn checked_arithmetic_reports_reduction() {
+        let n_minus_one = -S::ONE;
+        let two = S::from_words([2, 0, 0, 0]).unwrap();
+
+        assert_eq!(
+            S::ONE.checked_add(&two),
+            Some(S::from_words([3, 0, 0, 0]).unwrap())
+        );
+        assert_eq!(n_minus_one.checked_add(&S::ZERO), Some(n_minus_one));
+        assert_eq!(n_minus_one.checked_add(&S::ONE), None);
+
+        assert_eq!(
+            two.checked_mul(&two),
+            Some(S::from_words([4, 0, 0, 0]).unwrap())
+        );
+        assert_eq!(n_minus_one.checked_mul(&S::ONE), Some(n_minus_one));
+        assert_eq!(n_minus_one.checked_mul(&two), None);
+        assert_eq!(n_minus_one.checked_mul(&n_minus_one), None);
+
+        let order = Secp256k1::ORDER.to_be_byte_array();
+        assert_eq!(S::reduce_from_be_bytes(&order.into()), (S::ZERO, true));
+        let below: [u8; 32] = n_minus_one.to_be_bytes().try_into().unwrap();
+        assert_eq!(S::reduce_from_be_bytes(&below), (n_minus_one, false));
+    }
+
+    #[test]
+    fn zero_and_one_constants() {
+        assert!(S::ZERO.is_zero() && !S::ZERO.is_one());
+        assert!(S::ONE.is_one() && !S::ONE.is_zero());
+        assert_eq!(S::ZERO, S::default());
+        assert_eq!(*P::generator() * S::ONE, *P::generator());
+        assert!((S::ONE + -S::ONE).is_zero());
+
+        let one = Secp256k1FieldElement::ONE;
+        assert!(Secp256k1FieldElement::ZERO.is_zero());
+        assert!(one.is_one() && !one.is_zero());
+        assert!((Secp256k1FieldElement::ZERO + 1).is_one());
+        assert!((one + -one).is_zero());
+    }
+
+    #[test]
+    fn comb_assign_matches_lin_comb() {
+        let g = *P::generator();
+        let two_g = g + g;
+        let a = S::from_words([3, 0, 0, 0]).unwrap();
+        let b = S::from_words([5, 0, 0, 0]).unwrap();
+
+        let mut acc = two_g;
+        acc.comb_assign(&a, &g, &b);
+        assert_eq!(acc, P::lin_comb(&a, &two_g, &b, &g));
+
+        acc.comb_assign(&b, &g, &a);
+        assert_eq!(acc, g * S::from_words([58, 0, 0, 0]).unwrap());
+    }
+
+    #[test]
+    fn every_encoding_roundtrips() {
+        let encodings = [Encoding::BigEndian, Encoding::LittleEndian];
+
+        let g = *P::generator();
+        let (x_be, y_be) = g.to_affine_be();
+        let x = g.x();
+        assert_eq!(x.to_bytes(Encoding::BigEndian), x_be);
+        let mut x_le = x_be;
+        x_le.reverse();
+        assert_eq!(x.to_bytes(Encoding::LittleEndian), x_le);
+        for encoding in encodings {
+            assert_eq!(
+                Secp256k1FieldElement::from_bytes_strict(&x.to_bytes(encoding), encoding),
+                Some(x)
+            );
+        }
+
+        let s = S::from_words([1, 2, 3, 4]).unwrap();
+        assert_eq!(s.to_bytes(Encoding::BigEndian).to_vec(), s.to_be_bytes());
+        for encoding in encodings {
+            assert_eq!(
+                S::from_bytes_strict(&s.to_bytes(encoding), encoding),
+                Some(s)
+            );
+        }
+        assert_eq!(S::from_bytes_strict(&[0xff; 32], Encoding::BigEndian), None);
+
+        assert_eq!(g.to_bytes(Encoding::BigEndian)[..], [x_be, y_be].concat());
+        for encoding in encodings {
+            assert_eq!(P::from_bytes(&g.to_bytes(encoding), encoding), Ok(g));
+        }
+
+        let mut off_curve = g.to_bytes(Encoding::BigEndian);
+        off_curve[63] ^= 1;
+        assert_eq!(
+            P::from_bytes(&off_curve, Encoding::BigEndian),
+            Err(Error::InvalidPoint)
+        );
+    }
+
+    #[test]
+    fn is_square_agrees_with_sqrt() {
+        for i in 0..16u64 {
+            let x = Secp256k1FieldElement::default() + i;
+            assert_eq!(x.is_square(), x.sqrt().is_some());
+        }
+
+        let (x, _) = P::generator().to_affine_be();
+        let x = Secp256k1FieldElement::from_be_bytes(&x).unwrap();
+        assert!((x * x * x + 7).is_square());
+    }
+
+    #[test]
+    fn affine_be_export_matches_generator() {
+        let (x, y) = P::generator().to_affine_be();
+        assert_eq!(&x[..4], &[0x79, 0xbe, 0x66, 0x7e]);
+        assert_eq!(&y[..4], &[0x48, 0x3a, 0xda, 0x77]);
+
+        let (x_u256, y_u256) = P::generator().to_affine_u256();
+        assert_eq!(x_u256, U256::from_be_slice(&x));
+        assert_eq!(y_u256, U256::from_be_slice(&y));
+    }
+
+    #[test]
+    fn tweaks_commute_with_public_key_derivation() {
+        let secret = S::from_words([5, 0, 0, 0]).unwrap();
+        let tweak = S::from_words([7, 0, 0, 0]).unwrap();
+        let public = *P::generator() * secret;
+
+        assert_eq!(
+            public.tweak_add(&tweak),
+            secret.tweak_add(&tweak).map(|s| *P::generator() * s)
+        );
+        assert_eq!(
+            public.tweak_mul(&tweak),
+            secret.tweak_mul(&tweak).map(|s| *P::generator() * s)
+        );
+
+        assert!(public.tweak_add(&-secret).is_none());
+        assert!(secret.tweak_add(&-secret).is_none());
+        assert!(public.tweak_mul(&S::default()).is_none());
+        assert!(secret.tweak_mul(&S::default()).is_none());
+    }
+
+    #[test]
+    fn mul_small_matches_scalar_multiplication() {
+        let g = *P::generator();
+        for k in (0..=16).chain([255, u64::MAX]) {
+            assert_eq!(g.mul_small(k), g * S::from_words([k, 0, 0, 0]).unwrap());
+        }
+        assert_eq!(P::neutral().mul_small(2), P::neutral());
+    }
+
+    #[test]
+    fn endomorphism_and_glv_split() {
+        let lambda = S::LAMBDA;
+        assert!((lambda * lambda * lambda).is_one());
+
+        let g = *P::generator();
+        assert_eq!(g.mul_by_lambda(), g * lambda);
+        assert_eq!(
+            g.mul_by_lambda().mul_by_lambda(),
+            g * lambda.mul_by_lambda()
+        );
+        assert_eq!(P::neutral().mul_by_lambda(), P::neutral());
+
+        let bound = U256::ONE.shl_vartime(128);
+        let short = |s: S| U256::from(s) < bound || U256::from(-s) < bound;
+        for k in [
+            S::ONE,
+            -S::ONE,
+            lambda,
+            S::from_words([0x0123456789abcdef, u64::MAX, 42, 0xfedcba9876543210]).unwrap(),
+            P::reduce_hash(&[0xa5; 32]),
+        ] {
+            let (k1, k2) = k.split();
+            assert_eq!(k1 + k2 * lambda, k);
+            assert!(short(k1) && short(k2));
+        }
+    }
+
+    #[test]
+    fn pay_to_contract_commitment() {
+        let secret = S::from_words([5, 0, 0, 0]).unwrap();
+        let base = *P::generator() * secret;
+
+        let tweak = P::commitment_tweak(&base, b"contract");
+        let derived = *P::generator() * (secret + tweak);
+        assert!(P::verify_commitment(&base, b"contract", &derived));
+        assert!(!P::verify_commitment(&base, b"contracts", &derived));
+        assert!(!P::verify_commitment(&derived, b"contract", &derived));
+    }
+
+    #[test]
+    fn from_seed_is_reproducible_and_domain_separated() {
+        let seed = [0x5e; 32];
+
+        let s = S::from_seed(seed, "test/scalar");
+        assert_eq!(s, S::from_seed(seed, "test/scalar"));
+        assert_ne!(s, S::from_seed(seed, "test/other"));
+        assert_ne!(s, S::from_seed([0x5f; 32], "test/scalar"));
+        assert!(!s.is_zero());
+
+        let p = P::from_seed(seed, "test/point");
+        assert_eq!(p, P::from_seed(seed, "test/point"));
+        assert_ne!(p, P::from_seed(seed, "test/other"));
+        assert!(!p.y_is_odd());
+        assert_eq!(P::from_sec1(&p.to_sec1_bytes(false)), Ok(p));
+    }
+
+    #[test]
+    fn recover_compressed_matches_sec1_encoding() {
+        let KnownAnswer {
+            hash,
+            public_key,
+            signature,
+            recovery_id,
+            ..
+        } = known_answer();
+
+        let compressed = ECDSA::recover_compressed(&hash, &signature, &recovery_id).unwrap();
+        assert_eq!(compressed.to_vec(), public_key.to_sec1_bytes(true));
+
+        let flipped = RecoveryId::new(recovery_id.to_byte() ^ 1).unwrap();
+        let compressed = ECDSA::recover_compressed(&hash, &signature, &flipped).unwrap();
+        assert_ne!(compressed.to_vec(), public_key.to_sec1_bytes(true));
+    }
+
+    #[test]
+    fn compressed_and_address_recovery_reject_zero_components() {
+        let KnownAnswer {
+            hash,
+            signature,
+            recovery_id,
+            ..
+        } = known_answer();
+
+        for zeroed in [
+            Signature {
+                r: S::ZERO,
+                s: signature.s,
+            },
+            Signature {
+                r: signature.r,
+                s: S::ZERO,
+            },
+        ] {
+            assert_eq!(
+                ECDSA::recover_compressed(&hash, &zeroed, &recovery_id),
+                Err(Error::InvalidScalar)
+            );
+            assert_eq!(
+                RecoverableSignature::from((zeroed, recovery_id)).recover_address(&hash),
+                Err(Error::InvalidScalar)
+            );
+        }
+    }
+
+    #[test]
+    fn verify_with_address_compares_recovered_address() {
+        let KnownAnswer {
+            hash,
+            public_key,
+            signature,
+            recovery_id,
+            ..
+        } = known_answer();
+        let recoverable = RecoverableSignature::from((signature, recovery_id));
+
+        let address = public_key.to_eth_address();
+        assert_eq!(recoverable.recover_address(&hash), Ok(address));
+        assert!(ECDSA::verify_with_address(&hash, &recoverable, &address));
+        assert!(!ECDSA::verify_with_address(
+            &hash,
+            &recoverable,
+            &P::generator().to_eth_address()
+        ));
+
+        let mut tampered = hash;
+        tampered[0] ^= 1;
+        assert!(!ECDSA::verify_with_address(
+            &tampered,
+            &recoverable,
+            &address
+        ));
+
+        // (r, -s) with the opposite parity recovers the same key, but is high-s
+        let malleated = RecoverableSignature::from((
+            Signature {
+                r: signature.r,
+                s: -signature.s,
+            },
+            RecoveryId::new(recovery_id.to_byte() ^ 1).unwrap(),
+        ));
+        assert_eq!(malleated.recover_address(&hash), Ok(address));
+        assert!(!ECDSA::verify_with_address(&hash, &malleated, &address));
+    }
+
+    #[test]
+    fn verify_with_pubkey_hash_compares_recovered_hash() {
+        // HASH160 of the generator, i.e. of the secret key 1, behind the
+        // addresses 1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH and
+        // 1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm
+        let g = *P::generator();
+        assert_eq!(
+            hex::encode(g.to_pubkey_hash(true)),
+            "751e76e8199196d454941c45d1b3a323f1433bd6"
+        );
+        assert_eq!(
+            hex::encode(g.to_pubkey_hash(false)),
+            "91b24bf9f5288532960ac687abb035127b1d28a5"
+        );
+
+        let KnownAnswer {
+            hash,
+            public_key,
+            signature,
+            recovery_id,
+            ..
+        } = known_answer();
+        let recoverable = RecoverableSignature::from((signature, recovery_id));
+
+        for compress in [true, false] {
+            let pubkey_hash = public_key.to_pubkey_hash(compress);
+            assert_eq!(
+                recoverable.recover_pubkey_hash(&hash, compress),
+                Ok(pubkey_hash)
+            );
+            assert!(ECDSA::verify_with_pubkey_hash(
+                &hash,
+                &recoverable,
+                &pubkey_hash,
+                compress
+            ));
+            assert!(!ECDSA::verify_with_pubkey_hash(
+                &hash,
+                &recoverable,
+                &pubkey_hash,
+                !compress
+            ));
+            assert!(!ECDSA::verify_with_pubkey_hash(
+                &[0; 32],
+                &recoverable,
+                &pubkey_hash,
+                compress
+            ));
+        }
+
+        let high_s = RecoverableSignature::from((
+            Signature {
+                r: signature.r,
+                s: -signature.s,
+            },
+            RecoveryId::new(recovery_id.to_byte() ^ 1).unwrap(),
+        ));
+        assert!(!ECDSA::verify_with_pubkey_hash(
+            &hash,
+            &high_s,
+            &public_key.to_pubkey_hash(true),
+            true
+        ));
+    }
+
+    #[test]
+    fn reduce_u256_wraps_at_order_and_modulus() {
+        let one = U256::ONE;
+        assert!(reduce_u256_mod_n(ORDER_U256).is_zero());
+        assert_eq!(reduce_u256_mod_n(ORDER_U256.wrapping_add(&one)), S::ONE);
+        assert_eq!(
+            U256::from(reduce_u256_mod_n(U256::MAX)),
+            U256::MAX.wrapping_sub(&ORDER_U256)
+        );
+
+        assert!(reduce_u256_mod_p(MODULUS_U256).is_zero());
+        assert_eq!(
+            reduce_u256_mod_p(MODULUS_U256.wrapping_add(&one)),
+            Secp256k1FieldElement::ONE
+        );
+        assert_eq!(
+            U256::from(reduce_u256_mod_p(U256::MAX)),
+            U256::MAX.wrapping_sub(&MODULUS_U256)
+        );
+    }
+
+    #[test]
+    fn x_reduced_flags_coordinates_above_order() {
+        let (x, reduced) = P::generator().x_reduced();
+        assert_eq!(x, P::generator().get_x_coord());
+        assert!(!reduced);
+
+        // Find a curve point with n <= x < p
+        let (k, point) = (0u64..)
+            .find_map(|k| {
+                let x = Secp256k1::ORDER.wrapping_add(&U256::from(k));
+                P::decompress(&x.to_le_byte_array(), false).map(|p| (k, p))
+            })
+            .unwrap();
+
+        assert_eq!(
+            point.x_reduced(),
+            (S::from_words([k, 0, 0, 0]).unwrap(), true)
+        );
+    }
+
+    #[test]
+    fn debug_output_is_big_endian_hex() {
+        assert_eq!(
+            format!("{:?}", P::generator()),
+            "Secp256k1Point(0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798)"
+        );
+        assert_eq!(format!("{:?}", P::neutral()), "Secp256k1Point(00)");
+
+        let s = S::from_words([0x2a, 0, 0, 0x0100000000000000]).unwrap();
+        let hex = format!("01{}2a", "00".repeat(30));
+        assert_eq!(format!("{:?}", s), format!("Secp256k1Scalar({hex})"));
+
+        let expected = if cfg!(feature = "redact") {
+            "SecretScalar([REDACTED])".to_string()
+        } else {
+            format!("SecretScalar({hex})")
+        };
+        assert_eq!(format!("{:?}", SecretScalar::from(s)), expected);
+    }
+
+    #[test]
+    fn canonicalize_signature_with_high_s() {
+        let mut one: [u8; 32] = [0; 32];
+        one[0] = 1;
+
+        let r = S::from_le_bytes(&one).unwrap();
+        let s = S::from_le_bytes(&one).unwrap();
+
+        let low = Signature::<P> { r, s };
+        let high = Signature::<P> { r, s: -s };
+
+        assert_ne!(low, high);
+        assert_eq!(low.canonicalize(), high.canonicalize());
+        assert_eq!(high.canonicalize(), low);
+    }
 }
@@ -1,29 +1,210 @@
-use std::ops::{Add, Mul, Neg};
+use std::{
+    cmp::Ordering,
+    ops::{Add, Mul, Neg},
+};
 
-use ff::PrimeField;
-use k256::FieldElement;
+use ff::Field;
+use k256::{elliptic_curve::bigint::Encoding as _, FieldElement, U256};
 
-use crate::elliptic_curve::{FromLeBytes, HasSqrt, IsOdd, MultiplicativeInverse};
+use super::Encoding;
+use crate::elliptic_curve::{
+    FromBeBytes, FromLeBytes, HasSqrt, IsOdd, MultiplicativeInverse, ToBeBytes,
+};
+use crate::error::Error;
 
-#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+/// The field modulus p
+pub const MODULUS_U256: U256 =
+    U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+
+/// (p - 3) / 4 as little-endian limbs
+const SQRT_RATIO_EXP: [u64; 4] = [
+    0xffffffffbfffff0b,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+    0x3fffffffffffffff,
+];
+
+/// (p - 1) / 2 as little-endian limbs
+const LEGENDRE_EXP: [u64; 4] = [
+    0xffffffff7ffffe17,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+    0x7fffffffffffffff,
+];
+
+#[derive(Debug, Copy, Clone, Default)]
 pub struct Secp256k1FieldElement(pub(crate) FieldElement);
 
 impl Secp256k1FieldElement {
+    pub const ZERO: Self = Secp256k1FieldElement(FieldElement::ZERO);
+    pub const ONE: Self = Secp256k1FieldElement(FieldElement::ONE);
+
+    #[deprecated(note = "use `to_bytes` with `Encoding::LittleEndian`")]
     #[inline(always)]
     pub fn to_repr(&self) -> [u8; 32] {
-        let mut repr: [u8; 32] = self.0.to_repr().into();
-        repr.reverse();
-        repr
+        self.to_bytes(Encoding::LittleEndian)
     }
 
+    #[deprecated(note = "use `from_bytes_strict` with `Encoding::LittleEndian`")]
     #[inline(always)]
     pub fn from_repr(bytes: &[u8; 32]) -> Option<Self> {
-        let mut repr = bytes.clone();
-        repr.reverse();
-        FieldElement::from_bytes(&repr.into())
+        Secp256k1FieldElement::from_bytes_strict(bytes, Encoding::LittleEndian)
+    }
+
+    /// Interprets the bytes as an integer reduced modulo p. Unlike
+    /// [`Self::from_bytes_strict`], values `>= p` are accepted
+    #[inline(always)]
+    pub fn from_bytes_reduced(bytes: &[u8; 32], encoding: Encoding) -> Self {
+        let value = match encoding {
+            Encoding::BigEndian => U256::from_be_slice(bytes),
+            Encoding::LittleEndian => U256::from_le_slice(bytes),
+        };
+        reduce_u256_mod_p(value)
+    }
+
+    /// Decodes a field element from bytes in the given encoding, rejecting
+    /// values `>= p`
+    #[inline(always)]
+    pub fn from_bytes_strict(bytes: &[u8; 32], encoding: Encoding) -> Option<Self> {
+        let mut bytes_be = *bytes;
+        if encoding == Encoding::LittleEndian {
+            bytes_be.reverse();
+        }
+
+        FieldElement::from_bytes(&bytes_be.into())
             .into_option()
             .map(Secp256k1FieldElement)
     }
+
+    /// Encodes the canonical value in the given encoding
+    #[inline(always)]
+    pub fn to_bytes(&self, encoding: Encoding) -> [u8; 32] {
+        let mut bytes: [u8; 32] = self.0.to_bytes().into();
+        if encoding == Encoding::LittleEndian {
+            bytes.reverse();
+        }
+        bytes
+    }
+
+    /// Returns the canonical value as 64-bit limbs, least significant first.
+    /// The limbs are read straight off the normalized big-endian encoding,
+    /// without reversing into the little-endian encoding
+    #[inline(always)]
+    pub fn to_words(&self) -> [u64; 4] {
+        let bytes: [u8; 32] = self.0.to_bytes().into();
+        let mut words = [0u64; 4];
+        for (i, word) in words.iter_mut().enumerate() {
+            *word = u64::from_be_bytes(bytes[24 - 8 * i..32 - 8 * i].try_into().unwrap());
+        }
+        words
+    }
+
+    /// Builds a field element from 64-bit limbs, least significant first,
+    /// rejecting values `>= p`
+    #[inline(always)]
+    pub fn from_words(words: [u64; 4]) -> Option<Self> {
+        let mut bytes = [0u8; 32];
+        for (i, word) in words.iter().enumerate() {
+            bytes[24 - 8 * i..32 - 8 * i].copy_from_slice(&word.to_be_bytes());
+        }
+
+        FieldElement::from_bytes(&bytes.into())
+            .into_option()
+            .map(Secp256k1FieldElement)
+    }
+
+    /// Returns `sqrt(u / v)` if it exists, using a single exponentiation
+    /// instead of an inversion followed by a square root. As p = 3 mod 4,
+    /// the candidate is `u * (u * v)^((p - 3) / 4)`
+    pub fn sqrt_ratio(u: &Self, v: &Self) -> Option<Self> {
+        if v.is_zero() {
+            return None;
+        }
+
+        let uv = *u * *v;
+        let candidate = *u * Secp256k1FieldElement(uv.0.pow_vartime(SQRT_RATIO_EXP));
+
+        if candidate * candidate * *v == *u {
+            Some(candidate)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the element is a square modulo p, zero included, by
+    /// Euler's criterion. Cheaper than attempting [`HasSqrt::sqrt`] when only
+    /// the answer is needed
+    pub fn is_square(&self) -> bool {
+        let legendre = Secp256k1FieldElement(self.0.pow_vartime(LEGENDRE_EXP));
+        legendre != -Secp256k1FieldElement::ONE
+    }
+
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        self.0.normalizes_to_zero().into()
+    }
+
+    #[inline(always)]
+    pub fn is_one(&self) -> bool {
+        *self == Self::ONE
+    }
+}
+
+impl TryFrom<U256> for Secp256k1FieldElement {
+    type Error = Error;
+
+    /// Rejects values `>= p`, see [`reduce_u256_mod_p`] to reduce instead
+    #[inline(always)]
+    fn try_from(value: U256) -> Result<Self, Self::Error> {
+        FieldElement::from_bytes(&value.to_be_bytes().into())
+            .into_option()
+            .map(Secp256k1FieldElement)
+            .ok_or(Error::InvalidFieldElement)
+    }
+}
+
+/// Reduces a 256-bit integer, such as an EVM word, modulo p
+#[inline(always)]
+pub fn reduce_u256_mod_p(value: U256) -> Secp256k1FieldElement {
+    // 2^256 < 2p, so a single subtraction reduces any 256-bit value
+    let reduced = if value >= MODULUS_U256 {
+        value.wrapping_sub(&MODULUS_U256)
+    } else {
+        value
+    };
+
+    Secp256k1FieldElement(FieldElement::from_bytes(&reduced.to_be_bytes().into()).unwrap())
+}
+
+impl From<Secp256k1FieldElement> for U256 {
+    #[inline(always)]
+    fn from(value: Secp256k1FieldElement) -> Self {
+        U256::from_be_bytes(value.0.to_bytes().into())
+    }
+}
+
+impl PartialEq for Secp256k1FieldElement {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.normalize() == other.0.normalize()
+    }
+}
+
+impl Eq for Secp256k1FieldElement {}
+
+impl PartialOrd for Secp256k1FieldElement {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Secp256k1FieldElement {
+    /// Compares the canonical values, i.e. the normalized big-endian encodings
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.to_bytes().cmp(&other.0.to_bytes())
+    }
 }
 
 impl IsOdd for Secp256k1FieldElement {
@@ -36,12 +217,26 @@ impl IsOdd for Secp256k1FieldElement {
 impl FromLeBytes for Secp256k1FieldElement {
     #[inline(always)]
     fn from_le_bytes(bytes: &[u8]) -> Option<Self> {
-        if bytes.len() != 32 {
-            return None;
-        }
+        Secp256k1FieldElement::from_bytes_strict(bytes.try_into().ok()?, Encoding::LittleEndian)
+    }
+}
+
+impl FromBeBytes for Secp256k1FieldElement {
+    #[inline(always)]
+    fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+        Secp256k1FieldElement::from_bytes_strict(bytes.try_into().ok()?, Encoding::BigEndian)
+    }
+}
 
-        let bytes_be: [u8; 32] = bytes.try_into().unwrap();
-        Secp256k1FieldElement::from_repr(&bytes_be)
+impl ToBeBytes for Secp256k1FieldElement {
+    #[inline(always)]
+    fn to_be_bytes(&self) -> Vec<u8> {
+        self.to_bytes(Encoding::BigEndian).to_vec()
+    }
+
+    #[inline(always)]
+    fn write_be_bytes(&self, out: &mut [u8]) {
+        out.copy_from_slice(&self.to_bytes(Encoding::BigEndian));
     }
 }
 
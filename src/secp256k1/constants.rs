@@ -12,6 +12,22 @@ pub const R_GEN: ([u8; 32], [u8; 32]) = {
     }
 };
 
+/// Cube root of unity modulo n, with `lambda * (x, y) = (beta * x, y)`,
+/// little-endian
+pub const LAMBDA: [u8; 32] =
+    decode_le(b"0x5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72");
+
+/// Cube root of unity modulo p matching [`LAMBDA`], little-endian
+pub const BETA: [u8; 32] =
+    decode_le(b"0x7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee");
+
+const fn decode_le(hex: &[u8]) -> [u8; 32] {
+    match const_hex::const_decode_to_array(hex) {
+        Ok(bytes) => reverse_array(bytes),
+        Err(_) => panic!("Failed to decode hex values"),
+    }
+}
+
 const fn reverse_array<T, const N: usize>(mut arr: [T; N]) -> [T; N]
 where
     T: Copy,
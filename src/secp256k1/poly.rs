@@ -0,0 +1,121 @@
+//! Univariate polynomials over the scalar field, for IPA/Bulletproofs-style
+//! verifiers. Polynomials are coefficient vectors, lowest degree first.
+
+use super::Secp256k1Scalar;
+use crate::elliptic_curve::MultiplicativeInverse;
+
+/// Evaluates the polynomial at `x` with Horner's rule
+pub fn evaluate(coeffs: &[Secp256k1Scalar], x: &Secp256k1Scalar) -> Secp256k1Scalar {
+    coeffs
+        .iter()
+        .rev()
+        .fold(Secp256k1Scalar::ZERO, |acc, c| acc * x + c)
+}
+
+/// Returns the monic polynomial `(X - x_1) * ... * (X - x_n)` vanishing on
+/// the given points
+pub fn vanishing(points: &[Secp256k1Scalar]) -> Vec<Secp256k1Scalar> {
+    let mut coeffs = vec![Secp256k1Scalar::ONE];
+    for x in points {
+        // Multiply by (X - x): shift up one degree and subtract x times the
+        // previous coefficients
+        coeffs.insert(0, Secp256k1Scalar::ZERO);
+        for i in 0..coeffs.len() - 1 {
+            coeffs[i] = coeffs[i] + -(coeffs[i + 1] * x);
+        }
+    }
+    coeffs
+}
+
+/// Returns the polynomial of degree below `xs.len()` through the points
+/// `(xs[i], ys[i])`, or `None` if the lengths differ or an `x` repeats.
+/// All Lagrange denominators share a single inversion
+pub fn interpolate(xs: &[Secp256k1Scalar], ys: &[Secp256k1Scalar]) -> Option<Vec<Secp256k1Scalar>> {
+    if xs.len() != ys.len() {
+        return None;
+    }
+
+    let z = vanishing(xs);
+    let quotients: Vec<_> = xs.iter().map(|x| divide_by_root(&z, x)).collect();
+
+    let mut denominators: Vec<_> = quotients
+        .iter()
+        .zip(xs)
+        .map(|(q, x)| evaluate(q, x))
+        .collect();
+    if denominators.iter().any(Secp256k1Scalar::is_zero) {
+        return None;
+    }
+    batch_invert(&mut denominators);
+
+    let mut coeffs = vec![Secp256k1Scalar::ZERO; xs.len()];
+    for ((q, y), d) in quotients.iter().zip(ys).zip(&denominators) {
+        let weight = *y * d;
+        for (c, q_c) in coeffs.iter_mut().zip(q) {
+            *c = *c + *q_c * weight;
+        }
+    }
+
+    Some(coeffs)
+}
+
+/// Divides `p` by `(X - root)`, assuming `root` is a root of `p`
+fn divide_by_root(p: &[Secp256k1Scalar], root: &Secp256k1Scalar) -> Vec<Secp256k1Scalar> {
+    let mut quotient = vec![Secp256k1Scalar::ZERO; p.len() - 1];
+    let mut carry = Secp256k1Scalar::ZERO;
+    for i in (1..p.len()).rev() {
+        carry = p[i] + carry * root;
+        quotient[i - 1] = carry;
+    }
+    quotient
+}
+
+/// Inverts every value with one field inversion (Montgomery's trick). The
+/// values must be non-zero
+fn batch_invert(values: &mut [Secp256k1Scalar]) {
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acc = Secp256k1Scalar::ONE;
+    for v in values.iter() {
+        prefix.push(acc);
+        acc = acc * v;
+    }
+
+    let mut inv = acc.inverse();
+    for (v, before) in values.iter_mut().zip(prefix).rev() {
+        let inverted = inv * before;
+        inv = inv * *v;
+        *v = inverted;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s(n: u64) -> Secp256k1Scalar {
+        Secp256k1Scalar::from_words([n, 0, 0, 0]).unwrap()
+    }
+
+    #[test]
+    fn evaluate_vanish_and_interpolate() {
+        // 1 + 2X + 3X^2 at X = 5
+        assert_eq!(evaluate(&[s(1), s(2), s(3)], &s(5)), s(86));
+        assert_eq!(evaluate(&[], &s(5)), Secp256k1Scalar::ZERO);
+
+        let xs = [s(1), s(2), s(3), -s(4)];
+        let z = vanishing(&xs);
+        assert_eq!(z.len(), xs.len() + 1);
+        assert!(xs.iter().all(|x| evaluate(&z, x).is_zero()));
+        assert_eq!(evaluate(&z, &s(5)), s(4 * 3 * 2 * 9));
+
+        let ys = [s(7), s(0), -s(3), s(11)];
+        let p = interpolate(&xs, &ys).unwrap();
+        assert_eq!(p.len(), xs.len());
+        for (x, y) in xs.iter().zip(&ys) {
+            assert_eq!(evaluate(&p, x), *y);
+        }
+
+        assert!(interpolate(&[s(1), s(1)], &[s(2), s(3)]).is_none());
+        assert!(interpolate(&xs, &ys[1..]).is_none());
+    }
+}
@@ -1,22 +1,91 @@
-use crate::elliptic_curve::{FromLeBytes, MultiplicativeInverse, ToLeBytes};
+use super::constants::LAMBDA;
+use super::{le_bytes_to_words, words_to_le_bytes, ONE};
+use crate::elliptic_curve::{
+    CheckedAdd, FromBeBytes, FromLeBytes, HasZero, MultiplicativeInverse, ToBeBytes, ToLeBytes,
+};
+use crate::error::Error;
+use crate::hashing::{Hash256, Sha256};
 use ff::PrimeField;
 use k256::{
     elliptic_curve::{
-        bigint::{ArrayEncoding, Encoding},
+        bigint::{ArrayEncoding, Encoding as _},
         ops::Reduce,
         Curve,
     },
     Scalar, Secp256k1, U256,
 };
-use std::ops::{Mul, Neg};
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Add, Mul, Neg},
+};
 use valida_intrinsics as intrinsics;
 
-const FRAC_MODULUS_2: U256 = Secp256k1::ORDER.shr_vartime(1);
+/// The group order n
+pub const ORDER_U256: U256 = Secp256k1::ORDER;
+
+const FRAC_MODULUS_2: U256 = ORDER_U256.shr_vartime(1);
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+// GLV decomposition constants, as in libsecp256k1 and k256: the negated
+// short basis vectors and round(2^384 * b / n) for each
+const MINUS_B1: U256 =
+    U256::from_be_hex("00000000000000000000000000000000e4437ed6010e88286f547fa90abfe4c3");
+const MINUS_B2: U256 =
+    U256::from_be_hex("fffffffffffffffffffffffffffffffe8a280ac50774346dd765cda83db1562c");
+const G1: U256 =
+    U256::from_be_hex("3086d221a7d46bcde86c90e49284eb153daa8a1471e8ca7fe893209a45dbb031");
+const G2: U256 =
+    U256::from_be_hex("e4437ed6010e88286f547fa90abfe4c4221208ac9df506c61571b4ae8ac47f71");
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
 pub struct Secp256k1Scalar(pub(crate) intrinsics::Secp256k1Scalar);
 
+impl fmt::Debug for Secp256k1Scalar {
+    /// Prints the value as big-endian hex
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secp256k1Scalar({})", hex::encode(self.to_be_bytes()))
+    }
+}
+
+/// A scalar holding a secret key or nonce. It differs from the wrapped
+/// scalar only in `Debug`, which prints `[REDACTED]` with the `redact`
+/// feature so secrets stay out of host logs
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct SecretScalar(pub Secp256k1Scalar);
+
+impl fmt::Debug for SecretScalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if cfg!(feature = "redact") {
+            write!(f, "SecretScalar([REDACTED])")
+        } else {
+            write!(f, "SecretScalar({})", hex::encode(self.0.to_be_bytes()))
+        }
+    }
+}
+
+impl From<Secp256k1Scalar> for SecretScalar {
+    fn from(scalar: Secp256k1Scalar) -> Self {
+        SecretScalar(scalar)
+    }
+}
+
 impl Secp256k1Scalar {
+    pub const ZERO: Self = Secp256k1Scalar(intrinsics::Secp256k1Scalar { value: [0; 32] });
+    pub const ONE: Self = Secp256k1Scalar(intrinsics::Secp256k1Scalar { value: ONE });
+
+    /// Eigenvalue of the secp256k1 endomorphism, a cube root of unity
+    pub const LAMBDA: Self = Secp256k1Scalar(intrinsics::Secp256k1Scalar { value: LAMBDA });
+
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+
+    #[inline(always)]
+    pub fn is_one(&self) -> bool {
+        *self == Self::ONE
+    }
+
     #[inline(always)]
     pub fn is_high(&self) -> bool {
         let u256 = U256::from_le_slice(&self.0.value);
@@ -24,13 +93,34 @@ impl Secp256k1Scalar {
     }
 }
 
+impl HasZero for Secp256k1Scalar {
+    const ZERO: Self = Secp256k1Scalar::ZERO;
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        Secp256k1Scalar::is_zero(self)
+    }
+}
+
+impl Hash for Secp256k1Scalar {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.value.hash(state);
+    }
+}
+
 impl Mul for Secp256k1Scalar {
     type Output = Self;
 
     #[inline(always)]
-    fn mul(mut self, rhs: Self) -> Self::Output {
-        intrinsics::muls_secp256k1(&rhs.0, &mut self.0);
-        self
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut product = self;
+        intrinsics::muls_secp256k1(&rhs.0, &mut product.0);
+
+        #[cfg(feature = "differential")]
+        crate::differential::check_scalar_mul(&self, &rhs, &product);
+
+        product
     }
 }
 
@@ -38,9 +128,14 @@ impl Mul<&Secp256k1Scalar> for Secp256k1Scalar {
     type Output = Self;
 
     #[inline(always)]
-    fn mul(mut self, rhs: &Self) -> Self::Output {
-        intrinsics::muls_secp256k1(&rhs.0, &mut self.0);
-        self
+    fn mul(self, rhs: &Self) -> Self::Output {
+        let mut product = self;
+        intrinsics::muls_secp256k1(&rhs.0, &mut product.0);
+
+        #[cfg(feature = "differential")]
+        crate::differential::check_scalar_mul(&self, rhs, &product);
+
+        product
     }
 }
 
@@ -49,65 +144,347 @@ impl MultiplicativeInverse for Secp256k1Scalar {
     fn inverse(&self) -> Self {
         let mut copied = *self;
         intrinsics::sinv_secp256k1(&mut copied.0);
+
+        #[cfg(feature = "differential")]
+        crate::differential::check_scalar_inverse(self, &copied);
+
         copied
     }
 }
 
+/// Byte order taken by every `from_bytes*`/`to_bytes` method of the scalar,
+/// base field and point types, so the convention is named at each call site
+/// rather than implied by the method. SEC1 point encodings have their own
+/// `from_sec1`/`to_sec1_bytes`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// Big-endian integers, as in SEC1, Bitcoin and Ethereum serializations
+    BigEndian,
+    /// Little-endian integers, the intrinsics' layout
+    LittleEndian,
+}
+
+/// Reduces the little-endian bytes modulo n
+#[deprecated(note = "use `Secp256k1Scalar::from_bytes_reduced` with `Encoding::LittleEndian`")]
 #[inline(always)]
 pub fn scalar_reduce(s: &[u8; 32]) -> Secp256k1Scalar {
-    let value = U256::from_le_slice(s);
-    let scalar = Scalar::reduce(value);
-    let u256: U256 = scalar.into();
-    Secp256k1Scalar(intrinsics::Secp256k1Scalar {
-        value: u256.to_le_byte_array().into(),
-    })
+    Secp256k1Scalar::from_bytes_reduced(s, Encoding::LittleEndian)
 }
 
 impl Secp256k1Scalar {
+    /// Interprets the bytes as an integer reduced modulo n. Unlike
+    /// [`Self::from_bytes_strict`], values `>= n` are accepted
     #[inline(always)]
-    pub fn create(value: [u8; 32]) -> Option<Self> {
-        let u256 = U256::from_le_slice(&value);
-        if u256 < Secp256k1::ORDER {
+    pub fn from_bytes_reduced(bytes: &[u8; 32], encoding: Encoding) -> Self {
+        let value = match encoding {
+            Encoding::BigEndian => U256::from_be_slice(bytes),
+            Encoding::LittleEndian => U256::from_le_slice(bytes),
+        };
+        reduce_u256_mod_n(value)
+    }
+
+    /// Derives a non-zero scalar from `seed`, reproducibly, by hashing it
+    /// under the tag `domain` and reducing modulo n. For fixtures and
+    /// property tests that need valid inputs without an RNG; the result is
+    /// as secret as the seed
+    pub fn from_seed(seed: [u8; 32], domain: &str) -> Self {
+        (0u32..)
+            .map(|counter| {
+                let mut hasher = Sha256::new_tagged(domain.as_bytes());
+                hasher.update(&seed);
+                hasher.update(&counter.to_be_bytes());
+                Self::from_bytes_reduced(&hasher.finalize(), Encoding::BigEndian)
+            })
+            .find(|scalar| !scalar.is_zero())
+            .unwrap()
+    }
+
+    /// Decodes a scalar from bytes in the given encoding, rejecting values
+    /// `>= n`
+    #[inline(always)]
+    pub fn from_bytes_strict(bytes: &[u8; 32], encoding: Encoding) -> Option<Self> {
+        let mut value = *bytes;
+        if encoding == Encoding::BigEndian {
+            value.reverse();
+        }
+
+        if U256::from_le_slice(&value) < Secp256k1::ORDER {
             Some(Secp256k1Scalar(intrinsics::Secp256k1Scalar { value }))
         } else {
             None
         }
     }
-}
 
-impl FromLeBytes for Secp256k1Scalar {
+    /// Encodes the scalar in the given encoding
+    #[inline(always)]
+    pub fn to_bytes(&self, encoding: Encoding) -> [u8; 32] {
+        let mut bytes = self.0.value;
+        if encoding == Encoding::BigEndian {
+            bytes.reverse();
+        }
+        bytes
+    }
+
+    /// Interprets the big-endian bytes as an integer reduced modulo n,
+    /// together with whether the reduction wrapped, i.e. whether the input
+    /// was `>= n`. BIP-32 derivation must reject such inputs
+    #[inline(always)]
+    pub fn reduce_from_be_bytes(bytes: &[u8; 32]) -> (Self, bool) {
+        (
+            Secp256k1Scalar::from_bytes_reduced(bytes, Encoding::BigEndian),
+            U256::from_be_slice(bytes) >= Secp256k1::ORDER,
+        )
+    }
+
+    /// Multiplies the canonical values as integers, returning `None` if the
+    /// product is `>= n` and so would need reducing
+    pub fn checked_mul(&self, rhs: &Secp256k1Scalar) -> Option<Self> {
+        let (lo, hi) = U256::from(*self).mul_wide(&U256::from(*rhs));
+        if hi != U256::ZERO {
+            return None;
+        }
+
+        Secp256k1Scalar::from_bytes_strict(&lo.to_le_byte_array().into(), Encoding::LittleEndian)
+    }
+
+    /// Returns the value as 64-bit limbs, least significant first
     #[inline(always)]
-    fn from_le_bytes(value: &[u8]) -> Option<Self> {
-        let u256 = U256::from_le_slice(&value);
-        if u256 < Secp256k1::ORDER {
-            Some(Secp256k1Scalar(intrinsics::Secp256k1Scalar {
-                value: value.try_into().ok()?,
-            }))
+    pub fn to_words(&self) -> [u64; 4] {
+        le_bytes_to_words(&self.0.value)
+    }
+
+    /// Builds a scalar from 64-bit limbs, least significant first, rejecting
+    /// values `>= n`
+    #[inline(always)]
+    pub fn from_words(words: [u64; 4]) -> Option<Self> {
+        Secp256k1Scalar::from_bytes_strict(&words_to_le_bytes(words), Encoding::LittleEndian)
+    }
+
+    /// Builds a scalar from little-endian bytes without checking it is below
+    /// n. The caller must guarantee the value is in range
+    #[cfg(feature = "unchecked")]
+    #[inline(always)]
+    pub fn from_le_bytes_unchecked(value: [u8; 32]) -> Self {
+        Secp256k1Scalar(intrinsics::Secp256k1Scalar { value })
+    }
+
+    /// Returns the bits of the scalar, least significant first
+    pub fn to_le_bits(&self) -> [bool; 256] {
+        let mut bits = [false; 256];
+        for (i, bit) in bits.iter_mut().enumerate() {
+            *bit = (self.0.value[i / 8] >> (i % 8)) & 1 == 1;
+        }
+        bits
+    }
+
+    /// Returns the non-adjacent form of the scalar, least significant digit
+    /// first. Equivalent to [`Self::to_wnaf`] with a window of 2
+    pub fn to_naf(&self) -> Vec<i8> {
+        self.to_wnaf(2)
+    }
+
+    /// Returns the width-`w` non-adjacent form of the scalar, least
+    /// significant digit first: every non-zero digit is odd, lies in
+    /// `(-2^(w-1), 2^(w-1))` and is followed by at least `w - 1` zeros.
+    /// The result has 257 digits, as the top digit may carry out of 256 bits
+    ///
+    /// # Panics
+    ///
+    /// If `w` is outside `2..=8`. Digits of wider windows do not fit an `i8`
+    pub fn to_wnaf(&self, w: usize) -> Vec<i8> {
+        assert!((2..=8).contains(&w), "window must be between 2 and 8 bits");
+
+        let bits = self.to_le_bits();
+        let bit = |i: usize| bits.get(i).map_or(0, |b| *b as i32);
+        let width = 1i32 << w;
+
+        let mut naf = vec![0i8; 257];
+        let mut carry = 0;
+        let mut pos = 0;
+        while pos < naf.len() {
+            let window = carry + (0..w).map(|j| bit(pos + j) << j).sum::<i32>();
+
+            if window & 1 == 0 {
+                pos += 1;
+                continue;
+            }
+
+            if window < width / 2 {
+                carry = 0;
+                naf[pos] = window as i8;
+            } else {
+                carry = 1;
+                naf[pos] = (window - width) as i8;
+            }
+
+            pos += w;
+        }
+
+        naf
+    }
+
+    #[inline(always)]
+    pub fn mul_by_lambda(&self) -> Self {
+        *self * Self::LAMBDA
+    }
+
+    /// Splits the scalar for GLV multiplication into `(k1, k2)` with
+    /// `k = k1 + k2 * lambda`, where each of `k_i` and `-k_i` is below 2^128
+    pub fn split(&self) -> (Self, Self) {
+        // round(k * g / 2^384)
+        let mul_shift = |g: &U256| {
+            let (_, hi) = U256::from(*self).mul_wide(g);
+            reduce_u256_mod_n(hi.shr_vartime(127).wrapping_add(&U256::ONE).shr_vartime(1))
+        };
+
+        let c1 = mul_shift(&G1) * reduce_u256_mod_n(MINUS_B1);
+        let c2 = mul_shift(&G2) * reduce_u256_mod_n(MINUS_B2);
+        let k2 = c1 + c2;
+        let k1 = *self + -(k2 * Self::LAMBDA);
+
+        (k1, k2)
+    }
+
+    /// Adds `tweak` to a secret key, as libsecp256k1's
+    /// `ec_seckey_tweak_add`. Fails if the result is zero
+    pub fn tweak_add(&self, tweak: &Secp256k1Scalar) -> Option<Self> {
+        let tweaked = *self + tweak;
+        if tweaked.is_zero() {
+            None
         } else {
+            Some(tweaked)
+        }
+    }
+
+    /// Multiplies a secret key by `tweak`, as libsecp256k1's
+    /// `ec_seckey_tweak_mul`. Fails if the tweak is zero
+    pub fn tweak_mul(&self, tweak: &Secp256k1Scalar) -> Option<Self> {
+        if tweak.is_zero() {
             None
+        } else {
+            Some(*self * tweak)
         }
     }
+
+    #[deprecated(note = "use `from_bytes_strict` with `Encoding::LittleEndian`")]
+    #[inline(always)]
+    pub fn create(value: [u8; 32]) -> Option<Self> {
+        Secp256k1Scalar::from_bytes_strict(&value, Encoding::LittleEndian)
+    }
+}
+
+impl FromLeBytes for Secp256k1Scalar {
+    #[inline(always)]
+    fn from_le_bytes(bytes: &[u8]) -> Option<Self> {
+        Secp256k1Scalar::from_bytes_strict(bytes.try_into().ok()?, Encoding::LittleEndian)
+    }
+}
+
+impl FromBeBytes for Secp256k1Scalar {
+    #[inline(always)]
+    fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+        Secp256k1Scalar::from_bytes_strict(bytes.try_into().ok()?, Encoding::BigEndian)
+    }
+}
+
+impl ToBeBytes for Secp256k1Scalar {
+    #[inline(always)]
+    fn to_be_bytes(&self) -> Vec<u8> {
+        self.to_bytes(Encoding::BigEndian).to_vec()
+    }
+
+    #[inline(always)]
+    fn write_be_bytes(&self, out: &mut [u8]) {
+        out.copy_from_slice(&self.to_bytes(Encoding::BigEndian));
+    }
+}
+
+impl TryFrom<U256> for Secp256k1Scalar {
+    type Error = Error;
+
+    /// Rejects values `>= n`, see [`reduce_u256_mod_n`] to reduce instead
+    #[inline(always)]
+    fn try_from(value: U256) -> Result<Self, Self::Error> {
+        Secp256k1Scalar::from_bytes_strict(&value.to_le_byte_array().into(), Encoding::LittleEndian)
+            .ok_or(Error::InvalidScalar)
+    }
+}
+
+/// Reduces a 256-bit integer, such as an EVM word, modulo n
+#[inline(always)]
+pub fn reduce_u256_mod_n(value: U256) -> Secp256k1Scalar {
+    Secp256k1Scalar::from_k256(Scalar::reduce(value))
+}
+
+impl From<Secp256k1Scalar> for U256 {
+    #[inline(always)]
+    fn from(value: Secp256k1Scalar) -> Self {
+        U256::from_le_slice(&value.0.value)
+    }
+}
+
+impl CheckedAdd for Secp256k1Scalar {
+    /// Adds the canonical values as integers, returning `None` if the sum is
+    /// `>= n` and so would need reducing
+    #[inline(always)]
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let sum = U256::from(*self).checked_add(&U256::from(*rhs))?;
+        Secp256k1Scalar::from_bytes_strict(&sum.to_le_byte_array().into(), Encoding::LittleEndian)
+    }
 }
 
 impl ToLeBytes for Secp256k1Scalar {
     #[inline(always)]
     fn to_le_bytes(&self) -> Vec<u8> {
-        self.0.value.to_vec()
+        self.to_bytes(Encoding::LittleEndian).to_vec()
     }
-}
 
-impl Neg for Secp256k1Scalar {
-    type Output = Secp256k1Scalar;
+    #[inline(always)]
+    fn write_le_bytes(&self, out: &mut [u8]) {
+        out.copy_from_slice(&self.0.value);
+    }
+}
 
+impl Secp256k1Scalar {
     #[inline(always)]
-    fn neg(self) -> Self::Output {
+    fn to_k256(self) -> Scalar {
         let u256 = U256::from_le_slice(&self.0.value);
         let bytes = u256.to_be_bytes();
-        let scalar = Scalar::from_repr(bytes.into()).unwrap();
-        let scalar = -scalar;
+        Scalar::from_repr(bytes.into()).unwrap()
+    }
+
+    #[inline(always)]
+    fn from_k256(scalar: Scalar) -> Self {
         let u256: U256 = scalar.into();
         Secp256k1Scalar(intrinsics::Secp256k1Scalar {
             value: u256.to_le_byte_array().into(),
         })
     }
 }
+
+impl Add for Secp256k1Scalar {
+    type Output = Secp256k1Scalar;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Secp256k1Scalar::from_k256(self.to_k256() + rhs.to_k256())
+    }
+}
+
+impl Add<&Secp256k1Scalar> for Secp256k1Scalar {
+    type Output = Secp256k1Scalar;
+
+    #[inline(always)]
+    fn add(self, rhs: &Self) -> Self::Output {
+        Secp256k1Scalar::from_k256(self.to_k256() + rhs.to_k256())
+    }
+}
+
+impl Neg for Secp256k1Scalar {
+    type Output = Secp256k1Scalar;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Secp256k1Scalar::from_k256(-self.to_k256())
+    }
+}
@@ -0,0 +1,96 @@
+//! Known-answer tests exercising the intrinsics behind the curve and ECDSA
+//! operations, cheap enough to run at guest startup.
+
+use crate::ecdsa::{RecoveryId, Signature, ECDSA};
+use crate::elliptic_curve::{FromBeBytes, FromLeBytes, HasGenerator};
+use crate::error::Error;
+use crate::secp256k1::{Secp256k1Point, Secp256k1Scalar};
+
+const fn decode<const N: usize>(hex: &[u8]) -> [u8; N] {
+    match const_hex::const_decode_to_array(hex) {
+        Ok(bytes) => bytes,
+        Err(_) => panic!("Failed to decode hex values"),
+    }
+}
+
+/// 2·G, uncompressed SEC1
+const TWO_G: [u8; 65] = decode(
+    b"04c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5\
+      1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
+);
+
+/// SHA-256("valida-secp256k1 self check")
+pub(crate) const HASH: [u8; 32] =
+    decode(b"10a153b76f6722a9a2d145c345ef1a494df1ad8730309e3dae6f3473c469240a");
+
+/// Public key for the secret key SHA-256("valida-secp256k1 self check key"),
+/// uncompressed SEC1
+pub(crate) const PUBLIC_KEY: [u8; 65] = decode(
+    b"04af2b0836b29e15cabacdd917479fbf00ade4fa5c12903d750925242296645da4\
+      6b3ce5e3ad8112f5ad51ccf121d3a0229bbceb64f4d8969528e8429785b95958",
+);
+
+/// RFC 6979 signature of `HASH` under that key, big-endian
+pub(crate) const R: [u8; 32] =
+    decode(b"d0e7102c4dfdbaee7ea7ed7c61942d2d8590d7e0a5ac00fb4087f5dd720d9b60");
+pub(crate) const S: [u8; 32] =
+    decode(b"36ec71c734c9b9f350f0b84a6410d584350c3bb1e109994caa58e0e4dc290158");
+pub(crate) const RECOVERY_ID: u8 = 1;
+
+fn check(passed: bool, test: &'static str) -> Result<(), Error> {
+    if passed {
+        Ok(())
+    } else {
+        Err(Error::SelfCheckFailed(test))
+    }
+}
+
+/// Runs a handful of known-answer tests (generator doubling, ECDSA
+/// verification and public-key recovery), returning the first that fails.
+/// Meant to assert that the intrinsics of a given Valida release behave as
+/// expected before trusting a long proof run
+pub fn self_check() -> Result<(), Error> {
+    let g = *Secp256k1Point::generator();
+    let two_g = Secp256k1Point::from_sec1(&TWO_G)?;
+
+    let mut two: [u8; 32] = [0; 32];
+    two[0] = 2;
+    let two = Secp256k1Scalar::from_le_bytes(&two).ok_or(Error::InvalidScalar)?;
+
+    check(g + g == two_g, "generator addition")?;
+    check(g * two == two_g, "generator multiplication")?;
+
+    let public_key = Secp256k1Point::from_sec1(&PUBLIC_KEY)?;
+    let signature = Signature::<Secp256k1Point> {
+        r: Secp256k1Scalar::from_be_bytes(&R).ok_or(Error::InvalidScalar)?,
+        s: Secp256k1Scalar::from_be_bytes(&S).ok_or(Error::InvalidScalar)?,
+    };
+
+    let mut tampered = HASH;
+    tampered[0] ^= 1;
+
+    check(
+        ECDSA::verify(&HASH, &signature, &public_key),
+        "ecdsa verification",
+    )?;
+    check(
+        !ECDSA::verify(&tampered, &signature, &public_key),
+        "ecdsa rejection",
+    )?;
+
+    let recovery_id = RecoveryId::new(RECOVERY_ID).ok_or(Error::InvalidRecoveryId)?;
+    check(
+        ECDSA::recover(&HASH, &signature, &recovery_id) == Ok(public_key),
+        "ecdsa recovery",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn self_check_passes() {
+        assert_eq!(self_check(), Ok(()));
+    }
+}
@@ -0,0 +1,62 @@
+//! Signatures shared by the unit tests.
+
+use crate::ecdsa::{RecoverableSignature, RecoveryId, Signature};
+use crate::elliptic_curve::{EcdsaCurve, FromBeBytes, HasGenerator, MultiplicativeInverse};
+use crate::secp256k1::{Secp256k1Point, Secp256k1Scalar};
+use crate::self_check::{HASH, PUBLIC_KEY, R, RECOVERY_ID, S};
+
+/// The RFC 6979 signature checked by [`crate::self_check`]
+pub(crate) struct KnownAnswer {
+    pub message: &'static [u8],
+    pub hash: [u8; 32],
+    pub public_key: Secp256k1Point,
+    pub signature: Signature<Secp256k1Point>,
+    pub recovery_id: RecoveryId,
+}
+
+pub(crate) fn known_answer() -> KnownAnswer {
+    KnownAnswer {
+        message: b"valida-secp256k1 self check",
+        hash: HASH,
+        public_key: Secp256k1Point::from_sec1(&PUBLIC_KEY).unwrap(),
+        signature: Signature {
+            r: Secp256k1Scalar::from_be_bytes(&R).unwrap(),
+            s: Secp256k1Scalar::from_be_bytes(&S).unwrap(),
+        },
+        recovery_id: RecoveryId::new(RECOVERY_ID).unwrap(),
+    }
+}
+
+/// Scalar from a small integer
+pub(crate) fn scalar(n: u64) -> Secp256k1Scalar {
+    Secp256k1Scalar::from_words([n, 0, 0, 0]).unwrap()
+}
+
+/// Signs `hash` under the secret `key` with the nonce `k`, normalized to
+/// low-s, together with the recovery id of the result
+pub(crate) fn sign(
+    key: Secp256k1Scalar,
+    k: Secp256k1Scalar,
+    hash: &[u8; 32],
+) -> RecoverableSignature<Secp256k1Point> {
+    let nonce = *Secp256k1Point::generator() * k;
+    let (r, x_reduced) = nonce.x_reduced();
+    let s = k.inverse() * (Secp256k1Point::reduce_hash(hash) + r * key);
+
+    let y_is_odd = nonce.y_is_odd() ^ s.is_high();
+    let recovery_id = RecoveryId::new(y_is_odd as u8 | (x_reduced as u8) << 1).unwrap();
+    RecoverableSignature::from((Signature { r, s }.canonicalize(), recovery_id))
+}
+
+/// Hash signed by [`small_signature`]
+pub(crate) const SMALL_HASH: [u8; 32] = [0x42; 32];
+
+/// The secret key 7 signing [`SMALL_HASH`] with the nonce 11, returned with
+/// its public key
+pub(crate) fn small_signature() -> (Secp256k1Point, RecoverableSignature<Secp256k1Point>) {
+    let key = scalar(7);
+    (
+        *Secp256k1Point::generator() * key,
+        sign(key, scalar(11), &SMALL_HASH),
+    )
+}
@@ -0,0 +1,81 @@
+//! Curve-side building blocks of threshold ECDSA (GG18/GG20/CGGMP-style)
+//! sessions: combining the signers' nonce and signature shares and checking
+//! the result against the aggregate public key.
+
+use crate::ecdsa::{Signature, ECDSA};
+use crate::elliptic_curve::{EcdsaCurve, Group, HasZero};
+
+/// Aggregates the signers' nonce shares into `R = R_1 + ... + R_n`
+pub fn aggregate_nonce<C: Group>(nonce_shares: &[C]) -> C {
+    nonce_shares
+        .iter()
+        .fold(C::neutral(), |acc, share| acc + *share)
+}
+
+/// Converts the aggregate nonce `R` into the signature component `r = R.x mod n`,
+/// or `None` if `r` is zero
+pub fn nonce_to_r<C: EcdsaCurve>(nonce: &C) -> Option<C::Scalar> {
+    let r = nonce.get_x_coord();
+
+    if r.is_zero() {
+        None
+    } else {
+        Some(r)
+    }
+}
+
+/// Assembles the signature `(r, s_1 + ... + s_n)` from the signers' shares,
+/// normalized to low-s
+pub fn assemble_signature<C: EcdsaCurve>(r: C::Scalar, s_shares: &[C::Scalar]) -> Signature<C> {
+    let s = s_shares
+        .iter()
+        .fold(C::Scalar::ZERO, |acc, share| acc + share);
+
+    Signature { r, s }.canonicalize()
+}
+
+/// Assembles the signature from the nonce and signature shares and checks it
+/// against the aggregate public key, returning it only if it verifies
+pub fn combine_and_verify<C: EcdsaCurve + 'static>(
+    hash: &[u8; 32],
+    nonce_shares: &[C],
+    s_shares: &[C::Scalar],
+    aggregate_key: &C,
+) -> Option<Signature<C>> {
+    let r = nonce_to_r(&aggregate_nonce(nonce_shares))?;
+    let signature = assemble_signature(r, s_shares);
+
+    if ECDSA::verify(hash, &signature, aggregate_key) {
+        Some(signature)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::{HasGenerator, MultiplicativeInverse};
+    use crate::secp256k1::Secp256k1Point as P;
+    use crate::test_fixtures::{scalar, SMALL_HASH};
+
+    #[test]
+    fn combine_two_party_shares() {
+        let hash = SMALL_HASH;
+        let key = scalar(7);
+        let aggregate_key = *P::generator() * key;
+
+        let (k_1, k_2) = (scalar(11), scalar(13));
+        let nonce_shares = [*P::generator() * k_1, *P::generator() * k_2];
+
+        let r = nonce_to_r(&aggregate_nonce(&nonce_shares)).unwrap();
+        let s = (k_1 + k_2).inverse() * (P::reduce_hash(&hash) + r * key);
+        let s_1 = scalar(5);
+        let s_2 = s + -s_1;
+
+        let signature = combine_and_verify(&hash, &nonce_shares, &[s_1, s_2], &aggregate_key);
+        assert_eq!(signature, Some(Signature { r, s }.canonicalize()));
+
+        assert!(combine_and_verify(&hash, &nonce_shares, &[s_1, s_1], &aggregate_key).is_none());
+    }
+}
@@ -0,0 +1,113 @@
+//! Shamir secret sharing with Feldman commitments, the verifiable secret
+//! sharing that DKG protocols and custody proofs build on. The dealer's
+//! polynomial is a coefficient vector, lowest degree first, whose constant
+//! term is the secret; any `coeffs.len()` shares reconstruct it. Guests have
+//! no randomness, so the dealer supplies the coefficients.
+
+use crate::elliptic_curve::{Group, HasGenerator, HasNeutral};
+use crate::secp256k1::{poly, Secp256k1Point, Secp256k1Scalar};
+
+/// A participant's share: the dealer's polynomial evaluated at `index`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Share {
+    /// Participant index, from 1; index 0 would be the secret itself
+    pub index: u32,
+    pub value: Secp256k1Scalar,
+}
+
+impl Share {
+    fn x(&self) -> Secp256k1Scalar {
+        Secp256k1Scalar::from_words([self.index as u64, 0, 0, 0]).unwrap()
+    }
+}
+
+/// Evaluates the dealer's polynomial at the indices `1..=n`
+pub fn split(coeffs: &[Secp256k1Scalar], n: u32) -> Vec<Share> {
+    (1..=n)
+        .map(|index| {
+            let x = Secp256k1Scalar::from_words([index as u64, 0, 0, 0]).unwrap();
+            Share {
+                index,
+                value: poly::evaluate(coeffs, &x),
+            }
+        })
+        .collect()
+}
+
+/// Recovers the secret by interpolating the shares at zero. Returns `None`
+/// if there are no shares or an index repeats. Fewer shares than the
+/// polynomial has coefficients yield an unrelated value, not an error
+pub fn reconstruct(shares: &[Share]) -> Option<Secp256k1Scalar> {
+    let xs: Vec<_> = shares.iter().map(Share::x).collect();
+    let ys: Vec<_> = shares.iter().map(|share| share.value).collect();
+
+    poly::interpolate(&xs, &ys)?.first().copied()
+}
+
+/// Feldman commitments `a_i * G` to the dealer's coefficients, published
+/// alongside the shares
+pub fn commit(coeffs: &[Secp256k1Scalar]) -> Vec<Secp256k1Point> {
+    coeffs
+        .iter()
+        .map(|coeff| *Secp256k1Point::generator() * *coeff)
+        .collect()
+}
+
+/// Checks `share.value * G = C_0 + index * C_1 + ... + index^t * C_t` against
+/// the dealer's commitments with a single multi-scalar multiplication.
+/// Shares at index 0 and empty commitments are rejected
+pub fn verify_share(commitments: &[Secp256k1Point], share: &Share) -> bool {
+    if share.index == 0 || commitments.is_empty() {
+        return false;
+    }
+
+    let x = share.x();
+    let mut powers = Vec::with_capacity(commitments.len() + 1);
+    let mut power = Secp256k1Scalar::ONE;
+    for _ in commitments {
+        powers.push(power);
+        power = power * x;
+    }
+    powers.push(-share.value);
+
+    let terms = powers
+        .iter()
+        .zip(commitments.iter().chain([Secp256k1Point::generator()]));
+    Secp256k1Point::lin_comb_iter(terms) == Secp256k1Point::neutral()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::scalar;
+
+    #[test]
+    fn shares_verify_and_reconstruct() {
+        let coeffs = [scalar(42), scalar(7), -scalar(3)];
+        let shares = split(&coeffs, 5);
+        let commitments = commit(&coeffs);
+
+        assert_eq!(commitments[0], *Secp256k1Point::generator() * scalar(42));
+        assert!(shares.iter().all(|share| verify_share(&commitments, share)));
+
+        assert_eq!(reconstruct(&shares[..3]), Some(scalar(42)));
+        assert_eq!(reconstruct(&shares[2..]), Some(scalar(42)));
+        assert_ne!(reconstruct(&shares[..2]), Some(scalar(42)));
+        assert_eq!(reconstruct(&[shares[0], shares[0]]), None);
+        assert_eq!(reconstruct(&[]), None);
+
+        let tampered = Share {
+            value: shares[1].value + scalar(1),
+            ..shares[1]
+        };
+        assert!(!verify_share(&commitments, &tampered));
+        assert!(!verify_share(&commitments[..2], &shares[1]));
+        assert!(!verify_share(&[], &shares[1]));
+
+        let secret = Share {
+            index: 0,
+            value: scalar(42),
+        };
+        assert!(!verify_share(&commitments, &secret));
+    }
+}
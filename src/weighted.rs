@@ -0,0 +1,104 @@
+//! Weighted checks for proof-of-stake committees, where each validator's
+//! key carries a stake: aggregating the weighted keys, and checking that the
+//! validators with valid signatures hold at least a threshold weight.
+
+use crate::ecdsa::{Signature, ECDSA};
+use crate::elliptic_curve::{EcdsaCurve, Group};
+
+/// Aggregates weighted keys into `w_1 * P_1 + ... + w_n * P_n` with a single
+/// multi-scalar multiplication. Returns `None` if the sum is the point at
+/// infinity, e.g. when every weight is zero, since that is no usable key
+pub fn aggregate_weighted<C: Group + PartialEq>(keys: &[(C, C::Scalar)]) -> Option<C> {
+    let aggregate = C::lin_comb_iter(keys.iter().map(|(key, weight)| (weight, key)));
+
+    if aggregate == C::neutral() {
+        None
+    } else {
+        Some(aggregate)
+    }
+}
+
+/// Sums the weights of the validators with a valid signature over `hash`.
+/// Signatures refer to validators by index, each validator counts at most
+/// once, and out-of-range indices or invalid signatures are skipped
+pub fn signed_weight<C: EcdsaCurve + 'static>(
+    hash: &[u8; 32],
+    validators: &[(C, u64)],
+    signatures: &[(usize, Signature<C>)],
+) -> u64 {
+    let mut counted = vec![false; validators.len()];
+    let mut weight = 0u64;
+
+    for (index, signature) in signatures {
+        let Some((key, key_weight)) = validators.get(*index) else {
+            continue;
+        };
+        if counted[*index] || !ECDSA::verify(hash, signature, key) {
+            continue;
+        }
+
+        counted[*index] = true;
+        weight = weight.saturating_add(*key_weight);
+    }
+
+    weight
+}
+
+/// Checks that validators holding at least `threshold` weight signed `hash`
+pub fn verify_weight_threshold<C: EcdsaCurve + 'static>(
+    hash: &[u8; 32],
+    validators: &[(C, u64)],
+    signatures: &[(usize, Signature<C>)],
+    threshold: u64,
+) -> bool {
+    signed_weight(hash, validators, signatures) >= threshold
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::HasGenerator;
+    use crate::secp256k1::Secp256k1Point as P;
+    use crate::test_fixtures::{scalar, sign, SMALL_HASH};
+
+    #[test]
+    fn weighted_aggregation_and_threshold() {
+        let g = *P::generator();
+        let keys = [scalar(7), scalar(8), scalar(9)];
+
+        let weighted: Vec<_> = keys.iter().map(|key| (g * *key, scalar(2))).collect();
+        assert_eq!(aggregate_weighted(&weighted), Some(g * scalar(48)));
+
+        let zero_weights: Vec<_> = weighted.iter().map(|(key, _)| (*key, scalar(0))).collect();
+        assert_eq!(aggregate_weighted(&zero_weights), None);
+        let cancelling = [(g, scalar(1)), (g, -scalar(1))];
+        assert_eq!(aggregate_weighted(&cancelling), None);
+        assert_eq!(aggregate_weighted::<P>(&[]), None);
+
+        let hash = SMALL_HASH;
+        let sign_hash = |key, k| sign(key, k, &hash).signature;
+
+        let validators: Vec<_> = keys
+            .iter()
+            .zip([10, 20, 30])
+            .map(|(key, weight)| (g * *key, weight))
+            .collect();
+        let signatures = [
+            (0, sign_hash(keys[0], scalar(11))),
+            (2, sign_hash(keys[2], scalar(13))),
+            // Duplicate, mismatched and out-of-range entries don't count
+            (2, sign_hash(keys[2], scalar(17))),
+            (1, sign_hash(keys[0], scalar(19))),
+            (3, sign_hash(keys[1], scalar(23))),
+        ];
+
+        assert_eq!(signed_weight(&hash, &validators, &signatures), 40);
+        assert!(verify_weight_threshold(&hash, &validators, &signatures, 40));
+        assert!(!verify_weight_threshold(
+            &hash,
+            &validators,
+            &signatures,
+            41
+        ));
+    }
+}
@@ -0,0 +1,133 @@
+//! Validation of signature witnesses handed to a guest: every entry is
+//! parsed, range-checked and verified against the outcome the host claims,
+//! so guests don't each reimplement the parsing edge cases.
+
+use std::fmt;
+
+use crate::ecdsa::{Signature, ECDSA};
+use crate::elliptic_curve::FromBeBytes;
+use crate::error::Error;
+use crate::secp256k1::{Secp256k1Point, Secp256k1Scalar};
+
+/// A signature check as supplied by the host
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureWitness {
+    /// SEC1-encoded public key, compressed or uncompressed
+    pub public_key: Vec<u8>,
+    /// Message hash, big-endian
+    pub hash: [u8; 32],
+    /// Big-endian `r || s`
+    pub signature: [u8; 64],
+    /// Whether the signature is expected to verify
+    pub expected: bool,
+}
+
+/// The first witness that failed validation, by index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WitnessError {
+    /// The public key or signature could not be parsed
+    Malformed { index: usize, error: Error },
+    /// Verification did not produce the expected outcome
+    UnexpectedOutcome { index: usize, expected: bool },
+}
+
+impl fmt::Display for WitnessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WitnessError::Malformed { index, error } => {
+                write!(f, "witness {index} is malformed: {error}")
+            }
+            WitnessError::UnexpectedOutcome { index, expected } => write!(
+                f,
+                "witness {index} was expected to {}verify",
+                if *expected { "" } else { "not " }
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WitnessError {}
+
+impl SignatureWitness {
+    /// Parses the witness and reports whether its signature verifies
+    pub fn verify(&self) -> Result<bool, Error> {
+        let public_key = Secp256k1Point::from_sec1(&self.public_key)?;
+        let signature = Signature::<Secp256k1Point> {
+            r: Secp256k1Scalar::from_be_bytes(&self.signature[..32]).ok_or(Error::InvalidScalar)?,
+            s: Secp256k1Scalar::from_be_bytes(&self.signature[32..]).ok_or(Error::InvalidScalar)?,
+        };
+
+        Ok(ECDSA::verify(&self.hash, &signature, &public_key))
+    }
+}
+
+/// Checks every witness, returning the first that is malformed or whose
+/// verification outcome differs from the expected one
+pub fn validate_all(witnesses: &[SignatureWitness]) -> Result<(), WitnessError> {
+    for (index, witness) in witnesses.iter().enumerate() {
+        let verified = witness
+            .verify()
+            .map_err(|error| WitnessError::Malformed { index, error })?;
+
+        if verified != witness.expected {
+            return Err(WitnessError::UnexpectedOutcome {
+                index,
+                expected: witness.expected,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{small_signature, SMALL_HASH};
+
+    fn witness(expected: bool) -> SignatureWitness {
+        let (public_key, signature) = small_signature();
+
+        SignatureWitness {
+            public_key: public_key.to_sec1_bytes(true),
+            hash: SMALL_HASH,
+            signature: signature.to_bytes().unwrap()[..64].try_into().unwrap(),
+            expected,
+        }
+    }
+
+    #[test]
+    fn validate_all_reports_first_failure() {
+        let mut tampered = witness(false);
+        tampered.hash[0] ^= 1;
+        assert_eq!(validate_all(&[witness(true), tampered.clone()]), Ok(()));
+
+        assert_eq!(
+            validate_all(&[witness(true), witness(false)]),
+            Err(WitnessError::UnexpectedOutcome {
+                index: 1,
+                expected: false
+            })
+        );
+
+        let mut malformed = witness(true);
+        malformed.signature[32..].fill(0xff);
+        assert_eq!(
+            validate_all(&[tampered, malformed]),
+            Err(WitnessError::Malformed {
+                index: 1,
+                error: Error::InvalidScalar
+            })
+        );
+
+        let mut bad_key = witness(true);
+        bad_key.public_key[0] = 0x05;
+        assert_eq!(
+            validate_all(&[bad_key]),
+            Err(WitnessError::Malformed {
+                index: 0,
+                error: Error::InvalidPoint
+            })
+        );
+    }
+}